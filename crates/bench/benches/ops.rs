@@ -258,6 +258,42 @@ where T: RollingWindowBounds + FromStr<Err: Debug> {
     ));
 }
 
+#[allow(non_snake_case)]
+fn bench_add_slices<T>(c: &mut Criterion, label: &str, scalar: bool)
+where T: AddSlicesWrapper + Clone + FromStr<Err: Debug> {
+    let a_cfg = Series { seed: 7, ..Series::new(0..=18, 0..=19) };
+    let b_cfg = Series { seed: 17, ..Series::new(0..=18, 0..=19) };
+    let a_series = validator::series_str::<fixed_num>(a_cfg);
+    let b_series = validator::series_str::<fixed_num>(b_cfg);
+    let a_vec = a_series.iter().map(|s| T::from_str(s).unwrap()).collect::<Vec<T>>();
+    let b_vec = b_series.iter().map(|s| T::from_str(s).unwrap()).collect::<Vec<T>>();
+    let mut out = a_vec.clone();
+    c.bench_function(label, |bencher| bencher.iter(|| {
+        if scalar {
+            T::add_scalar_wrapper(black_box(&a_vec), black_box(&b_vec), &mut out);
+        } else {
+            T::add_slices_wrapper(black_box(&a_vec), black_box(&b_vec), &mut out);
+        }
+        black_box(&out);
+    }));
+}
+
+#[allow(non_snake_case)]
+fn bench_to_f64<T>(c: &mut Criterion, label: &str, fast: bool)
+where T: ToF64Wrapper + FromStr<Err: Debug> {
+    let series = validator::series_str::<fixed_num>(Series::new(0..=19, 0..=19));
+    let values = series.iter().map(|s| T::from_str(s).unwrap()).collect::<Vec<T>>();
+    c.bench_function(label, |bencher| bencher.iter(||
+        for a in values.iter() {
+            if fast {
+                black_box(a.to_f64_fast_wrapper());
+            } else {
+                black_box(a.to_f64_wrapper());
+            }
+        }
+    ));
+}
+
 // ==================
 // === Benchmarks ===
 // ==================
@@ -378,6 +414,11 @@ def_benches! { [rust_decimal, bigdecimal, decimal, decimal_rs, fastnum]
             |a| { for i in 2 .. 16 { a.powi_wrapper(i); } a.clone() }
         )
     }
+    scale_by_pow10 for [] {
+        bench1(Series::new(0..=9, 0..=19),
+            |a| { for i in -9 .. 9 { a.scale_by_pow10_wrapper(i); } a.clone() }
+        )
+    }
     sqrt for [rust_decimal, bigdecimal, decimal_rs, fastnum] {
         bench1(Series::new(0..=19, 0..=19),
             |a| a.abs().sqrt_wrapper()
@@ -396,6 +437,18 @@ def_benches! { [rust_decimal, bigdecimal, decimal, decimal_rs, fastnum]
     rolling_window for [rust_decimal, bigdecimal, decimal_rs, fastnum] {
         bench_rolling_window()
     }
+    add_slices for [] {
+        bench_add_slices(false)
+    }
+    add_slices_scalar for [] {
+        bench_add_slices(true)
+    }
+    to_f64 for [] {
+        bench_to_f64(false)
+    }
+    to_f64_fast for [] {
+        bench_to_f64(true)
+    }
 }
 
 // ================
@@ -444,6 +497,32 @@ wrapper! {
     }
 }
 
+pub trait AddSlicesWrapper: Sized + Copy + std::ops::Add<Output = Self> {
+    fn add_slices_wrapper(a: &[Self], b: &[Self], out: &mut [Self]);
+
+    /// Element-wise addition with the `Add` operator, the baseline for `add_slices_wrapper`.
+    #[inline(always)]
+    fn add_scalar_wrapper(a: &[Self], b: &[Self], out: &mut [Self]) {
+        for ((a, b), out) in a.iter().zip(b).zip(out.iter_mut()) {
+            *out = *a + *b;
+        }
+    }
+}
+
+impl AddSlicesWrapper for f64 {
+    #[inline(always)]
+    fn add_slices_wrapper(a: &[Self], b: &[Self], out: &mut [Self]) {
+        Self::add_scalar_wrapper(a, b, out);
+    }
+}
+
+impl AddSlicesWrapper for fixed_num {
+    #[inline(always)]
+    fn add_slices_wrapper(a: &[Self], b: &[Self], out: &mut [Self]) {
+        Self::add_slices(a, b, out);
+    }
+}
+
 wrapper! {
     trait SubWrapper {
         fn sub_wrapper(&self, other: &Self) -> Self {
@@ -667,6 +746,44 @@ wrapper! {
     }
 }
 
+wrapper! {
+    trait ScaleByPow10Wrapper {
+        fn scale_by_pow10_wrapper(&self, n: i32) -> Self {
+            f64          => self * 10f64.powi(n),
+            fixed_num    => self.scale_by_pow10(n),
+        }
+    }
+}
+
+pub trait ToF64Wrapper {
+    fn to_f64_wrapper(&self) -> f64;
+    fn to_f64_fast_wrapper(&self) -> f64;
+}
+
+impl ToF64Wrapper for f64 {
+    #[inline(always)]
+    fn to_f64_wrapper(&self) -> f64 {
+        *self
+    }
+
+    #[inline(always)]
+    fn to_f64_fast_wrapper(&self) -> f64 {
+        *self
+    }
+}
+
+impl ToF64Wrapper for fixed_num {
+    #[inline(always)]
+    fn to_f64_wrapper(&self) -> f64 {
+        f64::from(*self)
+    }
+
+    #[inline(always)]
+    fn to_f64_fast_wrapper(&self) -> f64 {
+        self.to_f64_fast()
+    }
+}
+
 wrapper! {
     trait PowiWrapper {
         fn powi_wrapper(&self, exp: i32) -> Self {
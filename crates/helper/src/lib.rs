@@ -86,7 +86,6 @@ pub fn shift_decimal(
     let mut int_part = int_part.to_string();
     let mut frac_part = frac_part.to_string();
 
-    #[expect(clippy::comparison_chain)]
     if exp > 0 {
         let exp = exp as usize;
         let move_count = exp.min(frac_part.len());
@@ -129,18 +128,39 @@ pub fn parse_dec19x19_internal(s: &str) -> Result<i128, ParseDec19x19Error> {
     let clean = s.replace(['_', ' '], "");
     let trimmed = clean.trim();
     let is_negative = trimmed.starts_with('-');
-    let e_parts: Vec<&str> = trimmed.split('e').collect();
+    // The sign is stripped upfront, so that shifting digits by the exponent never moves it.
+    let unsigned = trimmed.strip_prefix(['-', '+']).unwrap_or(trimmed);
+    let sign_len = trimmed.len() - unsigned.len();
+    if let Some(char) = unsigned.chars().next().filter(|c| *c == '-' || *c == '+') {
+        return Err(ParseDec19x19Error::InvalidChar { char, pos: sign_len })
+    }
+    let e_parts: Vec<&str> = unsigned.split('e').collect();
     if e_parts.len() > 2 {
-        let pos = e_parts[0].len() + e_parts[1].len() + 1;
+        let pos = sign_len + e_parts[0].len() + e_parts[1].len() + 1;
         return Err(ParseDec19x19Error::InvalidChar { char: 'e', pos })
     }
     let exp: i128 = e_parts.get(1).map_or(Ok(0), |t| t.parse())?;
     let parts: Vec<&str> = e_parts[0].split('.').collect();
     let parts_count = parts.len();
     if parts_count > 2 {
-        let pos = parts[0].len() + parts[1].len() + 1;
+        let pos = sign_len + parts[0].len() + parts[1].len() + 1;
         return Err(ParseDec19x19Error::InvalidChar { char: '.', pos })
     }
+    // Either the integer or the fractional part can be omitted (`5.` or `.5`), but not both, and
+    // an input without any digits (`""`, `"e5"`, or `"-"`) is rejected as well.
+    if parts.iter().all(|t| t.is_empty()) {
+        if parts_count == 2 {
+            return Err(ParseDec19x19Error::InvalidChar { char: '.', pos: sign_len })
+        }
+        if let Some(char) = unsigned.chars().next() {
+            return Err(ParseDec19x19Error::InvalidChar { char, pos: sign_len })
+        }
+        if let Some(char) = trimmed.chars().next() {
+            return Err(ParseDec19x19Error::InvalidChar { char, pos: 0 })
+        }
+        // Nothing to parse, reported the same way as by `i128::from_str("")`.
+        return parts[0].parse::<i128>().map_err(Into::into)
+    }
     let int_part_str = parts[0].to_string();
     let frac_part_str = parts.get(1).map(|t| t.to_string()).unwrap_or_default();
     let (int_part_str2, frac_part_str2) = shift_decimal(&int_part_str, &frac_part_str, exp);
@@ -158,7 +178,7 @@ pub fn parse_dec19x19_internal(s: &str) -> Result<i128, ParseDec19x19Error> {
     };
     let scaled = int_part.checked_mul(FRAC_SCALE_I128).ok_or(ParseDec19x19Error::OutOfBounds)?;
     let repr = if is_negative {
-        scaled.checked_sub(frac_part)
+        (-scaled).checked_sub(frac_part)
     } else {
         scaled.checked_add(frac_part)
     }.ok_or(ParseDec19x19Error::OutOfBounds)?;
@@ -176,13 +196,103 @@ pub struct Formatter {
     pub width: Option<usize>,
     pub align: Option<std::fmt::Alignment>,
     pub fill: char,
-    pub sign_plus: bool
+    pub sign_plus: bool,
+    /// The integer part is grouped only if it has more digits than this.
+    pub min_group_digits: usize,
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self {
+            separator: None,
+            precision: None,
+            width: None,
+            align: None,
+            fill: ' ',
+            sign_plus: false,
+            min_group_digits: 0,
+        }
+    }
 }
 
 pub trait Format {
     fn format(&self, f: &mut Formatter) -> String;
 }
 
+// ======================
+// === DisplayOptions ===
+// ======================
+
+/// Builder of formatting options, allowing to combine settings that can not be expressed with the
+/// standard [`Display`] flags, like a fixed number of fractional digits with digit grouping.
+///
+/// # Examples
+///
+/// ```
+/// # use fixed_num_helper::*;
+/// let opts = DisplayOptions::new().separator('_').precision(2).sign_plus(true);
+/// let formatter = opts.formatter();
+/// assert_eq!(formatter.separator, Some('_'));
+/// assert_eq!(formatter.precision, Some(2));
+/// assert!(formatter.sign_plus);
+/// assert_eq!(formatter.width, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayOptions {
+    formatter: Formatter,
+}
+
+impl DisplayOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Character used to group digits by three, both in the integer and fractional part.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.formatter.separator = Some(separator);
+        self
+    }
+
+    /// Number of fractional digits. The value is rounded or padded with zeros to match it.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.formatter.precision = Some(precision);
+        self
+    }
+
+    /// Minimal width of the output. Shorter outputs are padded with the [`Self::fill`] character.
+    pub fn width(mut self, width: usize) -> Self {
+        self.formatter.width = Some(width);
+        self
+    }
+
+    pub fn align(mut self, align: std::fmt::Alignment) -> Self {
+        self.formatter.align = Some(align);
+        self
+    }
+
+    pub fn fill(mut self, fill: char) -> Self {
+        self.formatter.fill = fill;
+        self
+    }
+
+    /// Whether to print the `+` sign for non-negative values.
+    pub fn sign_plus(mut self, sign_plus: bool) -> Self {
+        self.formatter.sign_plus = sign_plus;
+        self
+    }
+
+    /// Groups the integer part only if it has more than the given number of digits. For example,
+    /// with `4`, `1000` is printed without a separator, while `10,000` is still grouped.
+    pub fn min_group_digits(mut self, min_group_digits: usize) -> Self {
+        self.formatter.min_group_digits = min_group_digits;
+        self
+    }
+
+    pub fn formatter(&self) -> Formatter {
+        self.formatter
+    }
+}
+
 // ============
 // === Rand ===
 // ============
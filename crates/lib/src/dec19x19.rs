@@ -75,14 +75,110 @@ impl Dec19x19 {
     /// Creates a new `Dec19x19` from the given `i128` representation, assuming the last 19 digits
     /// are the fractional part.
     #[inline(always)]
+    #[must_use]
     pub const fn from_repr(repr: i128) -> Self {
         Self { repr }
     }
 
+    /// Creates a new `Dec19x19` equal to `int + frac / 10^19`. The fractional part has to be in
+    /// the range `0 .. 10^19`, so for negative values `int` is the floor of the value, e.g. `-3.25`
+    /// is created from `int = -4` and `frac = 0.75 * 10^19`. Returns [`None`] if `frac` is out of
+    /// range or the value does not fit in [`Dec19x19`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19::from_scaled(3, 2_500_000_000_000_000_000), Some(Dec19x19!(3.25)));
+    /// assert_eq!(Dec19x19::from_scaled(-4, 7_500_000_000_000_000_000), Some(Dec19x19!(-3.25)));
+    /// assert_eq!(Dec19x19::from_scaled(0, 1), Some(Dec19x19::SMALLEST_STEP));
+    /// assert_eq!(
+    ///     Dec19x19::from_scaled(-1, 9_999_999_999_999_999_999),
+    ///     Some(-Dec19x19::SMALLEST_STEP)
+    /// );
+    /// assert_eq!(Dec19x19::from_scaled(-7, 0), Some(Dec19x19!(-7)));
+    /// assert_eq!(
+    ///     Dec19x19::from_scaled(17_014_118_346_046_923_173, 1_687_303_715_884_105_727),
+    ///     Some(Dec19x19::MAX)
+    /// );
+    /// assert_eq!(
+    ///     Dec19x19::from_scaled(-17_014_118_346_046_923_174, 8_312_696_284_115_894_272),
+    ///     Some(Dec19x19::MIN)
+    /// );
+    ///
+    /// // Fractional part out of range.
+    /// assert_eq!(Dec19x19::from_scaled(3, 10_000_000_000_000_000_000), None);
+    /// assert_eq!(Dec19x19::from_scaled(3, -1), None);
+    ///
+    /// // Value out of range.
+    /// let max_int = 17_014_118_346_046_923_173;
+    /// assert_eq!(Dec19x19::from_scaled(max_int, 1_687_303_715_884_105_728), None);
+    /// assert_eq!(Dec19x19::from_scaled(max_int + 1, 0), None);
+    /// assert_eq!(Dec19x19::from_scaled(-max_int - 1, 8_312_696_284_115_894_271), None);
+    /// assert_eq!(Dec19x19::from_scaled(-max_int - 2, 9_999_999_999_999_999_999), None);
+    /// assert_eq!(Dec19x19::from_scaled(i128::MIN, 0), None);
+    /// ```
     #[inline(always)]
+    #[must_use]
+    pub const fn from_scaled(int: i128, frac: i128) -> Option<Self> {
+        if frac < 0 || frac >= FRAC_SCALE_I128 {
+            return None;
+        }
+        // For negative values, `int * 10^19` can overflow even if the final value is in range.
+        let (int, frac) =
+            if int < 0 && frac > 0 { (int + 1, frac - FRAC_SCALE_I128) } else { (int, frac) };
+        match int.checked_mul(FRAC_SCALE_I128) {
+            Some(scaled) => match scaled.checked_add(frac) {
+                Some(repr) => Some(Self { repr }),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    #[must_use]
     pub const fn is_zero(self) -> bool {
         self.repr == 0
     }
+
+    /// Always `true`, as every [`Dec19x19`] value is finite. Provided for compatibility with code
+    /// written against `f64`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert!(Dec19x19!(0).is_finite());
+    /// assert!(Dec19x19::MAX.is_finite());
+    /// assert!(Dec19x19::MIN.is_finite());
+    /// const FINITE: bool = Dec19x19!(-3.5).is_finite();
+    /// assert!(FINITE);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        true
+    }
+
+    /// Always `false`, as [`Dec19x19`] has no NaN value. Provided for compatibility with code
+    /// written against `f64`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert!(!Dec19x19!(0).is_nan());
+    /// assert!(!Dec19x19::MAX.is_nan());
+    /// assert!(!Dec19x19::MIN.is_nan());
+    /// const NAN: bool = Dec19x19!(-3.5).is_nan();
+    /// assert!(!NAN);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        false
+    }
 }
 
 // =================
@@ -127,6 +223,166 @@ impl PartialOrd for Dec19x19 {
     }
 }
 
+impl Dec19x19 {
+    /// The same as [`Ord::cmp`]. Provided for compatibility with code written for [`f64`], which
+    /// uses [`f64::total_cmp`] for sorting.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let mut values = [Dec19x19!(3.5), Dec19x19::MIN, Dec19x19!(-1), Dec19x19::MAX];
+    /// values.sort_by(|a, b| a.total_cmp(b));
+    /// let sorted = [Dec19x19::MIN, Dec19x19!(-1), Dec19x19!(3.5), Dec19x19::MAX];
+    /// assert_eq!(values, sorted);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp(other)
+    }
+
+    /// Restricts the value to the given range. The same as [`Ord::clamp`], but accepts a range,
+    /// e.g. `x.clamp_to(Dec19x19!(0) ..= Dec19x19!(1))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the range is empty (`start > end`). In release builds, `end` is
+    /// returned in such a case.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// let range = Dec19x19!(0) ..= Dec19x19!(1);
+    /// assert_eq!(Dec19x19!(-0.5).clamp_to(range.clone()), Dec19x19!(0));
+    /// assert_eq!(Dec19x19!(0.5).clamp_to(range.clone()), Dec19x19!(0.5));
+    /// assert_eq!(Dec19x19!(1.5).clamp_to(range.clone()), Dec19x19!(1));
+    /// assert_eq!(Dec19x19::MIN.clamp_to(range.clone()), Dec19x19!(0));
+    /// assert_eq!(Dec19x19::MAX.clamp_to(range), Dec19x19!(1));
+    /// assert_eq!(Dec19x19!(7).clamp_to(Dec19x19!(2) ..= Dec19x19!(2)), Dec19x19!(2));
+    /// if cfg!(debug_assertions) {
+    ///     should_panic(|| Dec19x19!(0.5).clamp_to(Dec19x19!(1) ..= Dec19x19!(0)), "empty range");
+    /// }
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn clamp_to(self, range: std::ops::RangeInclusive<Self>) -> Self {
+        let (start, end) = range.into_inner();
+        debug_assert!(start <= end, "Empty range: {start} > {end}");
+        self.max(start).min(end)
+    }
+
+    /// Restricts the value to the `0 ..= 1` range, e.g. to sanitize probabilities or weights.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check!( [Dec19x19::clamp01] {
+    ///     (Dec19x19!(-0.5)) => Dec19x19!(0),
+    ///     (Dec19x19!(0)) => Dec19x19!(0),
+    ///     (Dec19x19!(0.5)) => Dec19x19!(0.5),
+    ///     (Dec19x19!(1)) => Dec19x19!(1),
+    ///     (Dec19x19!(1.5)) => Dec19x19!(1),
+    ///     (Dec19x19::MIN) => Dec19x19!(0),
+    ///     (Dec19x19::MAX) => Dec19x19!(1),
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn clamp01(self) -> Self {
+        if self.repr < 0 {
+            Dec19x19!(0)
+        } else if self.repr > FRAC_SCALE_I128 {
+            Dec19x19!(1)
+        } else {
+            self
+        }
+    }
+
+    /// Returns `(min, max)` of the two values using a single comparison.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::minmax] {
+    ///     (Dec19x19!(1), Dec19x19!(2)) => (Dec19x19!(1), Dec19x19!(2)),
+    ///     (Dec19x19!(2), Dec19x19!(1)) => (Dec19x19!(1), Dec19x19!(2)),
+    ///     (Dec19x19!(-0.5), Dec19x19!(-0.5)) => (Dec19x19!(-0.5), Dec19x19!(-0.5)),
+    ///     (Dec19x19::MAX, Dec19x19::MIN) => (Dec19x19::MIN, Dec19x19::MAX),
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn minmax(self, other: Self) -> (Self, Self) {
+        if self.repr <= other.repr { (self, other) } else { (other, self) }
+    }
+
+    /// Positive difference, `max(self - other, 0)`, like C's `fdim`. Saturates to
+    /// [`Dec19x19::MAX`] if the difference does not fit.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::abs_sub] {
+    ///     (Dec19x19!(5), Dec19x19!(3)) => Dec19x19!(2),
+    ///     (Dec19x19!(3), Dec19x19!(5)) => Dec19x19!(0),
+    ///     (Dec19x19!(3), Dec19x19!(3)) => Dec19x19!(0),
+    ///     (Dec19x19!(-1.5), Dec19x19!(-2)) => Dec19x19!(0.5),
+    ///     (Dec19x19::MAX, Dec19x19!(-1)) => Dec19x19::MAX,
+    ///     (Dec19x19::MAX, Dec19x19::MIN) => Dec19x19::MAX,
+    ///     (Dec19x19::MIN, Dec19x19::MAX) => Dec19x19!(0),
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn abs_sub(self, other: Self) -> Self {
+        if self.repr <= other.repr {
+            Dec19x19!(0)
+        } else {
+            Self::from_repr(self.repr.saturating_sub(other.repr))
+        }
+    }
+
+    /// Checks whether the distance between the two values is at most `epsilon`. Useful after a
+    /// chain of operations like `div` or `sqrt`, where `==` is often too strict. The distance is
+    /// computed without overflow. A negative `epsilon` never matches.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// let step = Dec19x19::SMALLEST_STEP;
+    /// check! ( [Dec19x19::approx_eq] {
+    ///     (Dec19x19!(1), Dec19x19!(1), Dec19x19!(0)) => true,
+    ///     (Dec19x19!(1), Dec19x19!(1) + step, Dec19x19!(0)) => false,
+    ///     (Dec19x19!(1), Dec19x19!(1) + step, step) => true,
+    ///     (Dec19x19!(1), Dec19x19!(1) - step, step) => true,
+    ///     (Dec19x19!(1), Dec19x19!(1) + step + step, step) => false,
+    ///     (Dec19x19!(1) / Dec19x19!(3) * Dec19x19!(3), Dec19x19!(1), step) => true,
+    ///     (Dec19x19!(1.5), Dec19x19!(1.4), Dec19x19!(0.1)) => true,
+    ///     (Dec19x19!(1.5), Dec19x19!(1.4), Dec19x19!(0.099)) => false,
+    ///     (Dec19x19!(1), Dec19x19!(1), -step) => false,
+    ///     (Dec19x19::MIN, Dec19x19::MAX, Dec19x19::MAX) => false,
+    ///     (Dec19x19::MAX, Dec19x19::MAX, Dec19x19!(0)) => true,
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn approx_eq(self, other: Self, epsilon: Self) -> bool {
+        epsilon.repr >= 0 && self.repr.abs_diff(other.repr) <= epsilon.repr as u128
+    }
+}
+
 #[cfg(nightly)]
 impl std::iter::Step for Dec19x19 {
     #[inline(always)]
@@ -198,6 +454,10 @@ impl Dec19x19 {
     /// fixed-point format.
     pub const LN_2: Self = Dec19x19!(0.693_147_180_559_945_309_4);
 
+    /// The ratio of a circle's circumference to its diameter (`π`), accurate to all 19 decimal
+    /// places of the `Dec19x19` fixed-point format.
+    pub const PI: Self = Dec19x19!(3.141_592_653_589_793_238_5);
+
     /// The smallest possible value that can be stored in a `Dec19x19`.
     ///
     /// # Tests
@@ -214,7 +474,8 @@ impl Dec19x19 {
 // ==============
 
 /// Generates a deterministic random `Dec19x19` value using a seed, an integer precision, and a
-/// fractional precision. Never returns zero.
+/// fractional precision. Never returns zero, see [`Dec19x19::rand_allow_zero`] for a variant that
+/// can.
 ///
 /// # Tests
 ///
@@ -275,6 +536,41 @@ impl Dec19x19 {
 /// ```
 impl Rand for Dec19x19 {
     fn rand(seed: u64, int: impl IntoRandRange, frac: impl IntoRandRange) -> Self {
+        Self::rand_impl(seed, int, frac, false)
+    }
+}
+
+impl Dec19x19 {
+    /// Like [`Rand::rand`], but can return zero, e.g. to exercise the division by zero paths in
+    /// property tests. For the same arguments, the result is the same as of [`Rand::rand`], except
+    /// when the latter replaces zero with the smallest non-zero value of the chosen precision.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let is_zero = |seed| Dec19x19::rand_allow_zero(seed, 0, 0).is_zero();
+    /// let zeros = (0..100).filter(|&seed| is_zero(seed)).count();
+    /// assert!(zeros > 0);
+    /// assert!((0..100).all(|seed| !Dec19x19::rand(seed, 0, 0).is_zero()));
+    /// for seed in 0..100 {
+    ///     let t = Dec19x19::rand_allow_zero(seed, 0..=3, 0..=3);
+    ///     if !t.is_zero() {
+    ///         assert_eq!(t, Dec19x19::rand(seed, 0..=3, 0..=3));
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn rand_allow_zero(seed: u64, int: impl IntoRandRange, frac: impl IntoRandRange) -> Self {
+        Self::rand_impl(seed, int, frac, true)
+    }
+
+    fn rand_impl(
+        seed: u64,
+        int: impl IntoRandRange,
+        frac: impl IntoRandRange,
+        allow_zero: bool,
+    ) -> Self {
         let int_prec_range = int.into_rand_range();
         let frac_prec_range = frac.into_rand_range();
         assert!(*int_prec_range.end() <= 19);
@@ -297,7 +593,7 @@ impl Rand for Dec19x19 {
         let first_digit_start = if int_prec > 0 { 1 } else { 0 };
         let first_digit = rng.random_range(first_digit_start..=9);
         let mut val = first_digit * scale + rng.random_range(0..=max_val);
-        if val == 0 {
+        if val == 0 && !allow_zero {
             val = 1;
         }
 
@@ -309,6 +605,101 @@ impl Rand for Dec19x19 {
     }
 }
 
+impl Dec19x19 {
+    /// Generates a deterministic random value using a seed, uniformly distributed over all
+    /// representable values in `[lo, hi]`. Unlike [`Rand::rand`], which picks the number of digits
+    /// first, every value in the range is equally likely.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let ranges = [
+    ///     (Dec19x19!(-1), Dec19x19!(1)),
+    ///     (Dec19x19!(99.5), Dec19x19!(100.5)),
+    ///     (Dec19x19!(-1e-18), Dec19x19!(-1e-19)),
+    ///     (Dec19x19::MIN, Dec19x19::MAX),
+    ///     (Dec19x19::MAX - Dec19x19::SMALLEST_STEP, Dec19x19::MAX),
+    /// ];
+    /// for (lo, hi) in ranges {
+    ///     for seed in 0..100 {
+    ///         let t = Dec19x19::rand_range(seed, lo, hi);
+    ///         assert!(lo <= t && t <= hi);
+    ///         assert_eq!(t, Dec19x19::rand_range(seed, lo, hi));
+    ///     }
+    /// }
+    /// assert_eq!(Dec19x19::rand_range(7, Dec19x19!(3.5), Dec19x19!(3.5)), Dec19x19!(3.5));
+    /// // The values are not concentrated at small magnitudes.
+    /// let big = (0..100)
+    ///     .map(|seed| Dec19x19::rand_range(seed, Dec19x19!(0), Dec19x19!(1000)))
+    ///     .filter(|t| *t >= Dec19x19!(500))
+    ///     .count();
+    /// assert!((30..=70).contains(&big));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use fixed_num::*;
+    /// let _ = Dec19x19::rand_range(0, Dec19x19!(1), Dec19x19!(0));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn rand_range(seed: u64, lo: Self, hi: Self) -> Self {
+        assert!(lo <= hi, "Empty range in Dec19x19::rand_range: {lo} > {hi}");
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self::from_repr(rng.random_range(lo.repr..=hi.repr))
+    }
+
+    /// Generates a deterministic random value using a seed, uniformly distributed in
+    /// `[center - spread, center + spread]`, with the bounds clamped to the representable range.
+    /// Useful for test data clustered around a value, like prices near a mid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spread` is negative.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let cases = [
+    ///     (Dec19x19!(100), Dec19x19!(0.5)),
+    ///     (Dec19x19!(-3.25), Dec19x19!(10)),
+    ///     (Dec19x19!(0), Dec19x19::SMALLEST_STEP),
+    ///     (Dec19x19::MAX - Dec19x19!(1), Dec19x19!(5)),
+    ///     (Dec19x19::MIN, Dec19x19!(5)),
+    /// ];
+    /// for (center, spread) in cases {
+    ///     let lo = center.saturating_sub(spread);
+    ///     let hi = center.saturating_add(spread);
+    ///     for seed in 0..100 {
+    ///         let t = Dec19x19::rand_around(seed, center, spread);
+    ///         assert!(lo <= t && t <= hi);
+    ///         assert_eq!(t, Dec19x19::rand_around(seed, center, spread));
+    ///     }
+    /// }
+    /// assert_eq!(Dec19x19::rand_around(3, Dec19x19!(42), Dec19x19!(0)), Dec19x19!(42));
+    /// assert_eq!(
+    ///     Dec19x19::rand_around(3, Dec19x19!(42), Dec19x19!(1)),
+    ///     Dec19x19::rand_range(3, Dec19x19!(41), Dec19x19!(43)),
+    /// );
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use fixed_num::*;
+    /// let _ = Dec19x19::rand_around(0, Dec19x19!(1), Dec19x19!(-1));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn rand_around(seed: u64, center: Self, spread: Self) -> Self {
+        assert!(spread.repr >= 0, "Negative spread in Dec19x19::rand_around: {spread}");
+        Self::rand_range(seed, center.saturating_sub(spread), center.saturating_add(spread))
+    }
+}
+
 // ====================
 // === Impl Helpers ===
 // ====================
@@ -357,6 +748,17 @@ macro_rules! const_impl {
     };
 }
 
+/// Panics with a message naming the operator and both operands. Used by the default operators
+/// when the `debug_checked_ops` feature is enabled.
+#[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+#[cold]
+#[inline(never)]
+#[track_caller]
+#[allow(clippy::panic)]
+fn op_overflow(op: &str, lhs: Dec19x19, rhs: Dec19x19) -> ! {
+    panic!("Overflow in Dec19x19 {op}: lhs = {lhs}, rhs = {rhs}")
+}
+
 // =================
 // === Max / Min ===
 // =================
@@ -389,6 +791,115 @@ impl HasMin for Dec19x19 {
     }
 }}
 
+// ======================
+// === Next Up / Down ===
+// ======================
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::next_up] {
+///     (Dec19x19!(1))                => Dec19x19!(1.000_000_000_000_000_000_1),
+///     (Dec19x19!(0))                => Dec19x19::SMALLEST_STEP,
+///     (-Dec19x19::SMALLEST_STEP)    => Dec19x19!(0),
+///     (Dec19x19::MAX)               => Dec19x19::MAX,
+///     (Dec19x19::MIN)               => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+/// });
+/// check! ( [Dec19x19::next_down] {
+///     (Dec19x19!(1))                => Dec19x19!(0.999_999_999_999_999_999_9),
+///     (Dec19x19!(0))                => -Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::SMALLEST_STEP)     => Dec19x19!(0),
+///     (Dec19x19::MAX)               => Dec19x19::MAX - Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::MIN)               => Dec19x19::MIN,
+/// });
+/// check! ( [Dec19x19::checked_increment] {
+///     (Dec19x19!(1))                => Dec19x19!(1.000_000_000_000_000_000_1),
+///     (-Dec19x19::SMALLEST_STEP)    => Dec19x19!(0),
+///     (Dec19x19::MAX - Dec19x19::SMALLEST_STEP) => Dec19x19::MAX,
+///     (Dec19x19::MAX)               => FAIL,
+///     (Dec19x19::MIN)               => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+/// });
+/// check! ( [Dec19x19::checked_decrement] {
+///     (Dec19x19!(1))                => Dec19x19!(0.999_999_999_999_999_999_9),
+///     (Dec19x19::SMALLEST_STEP)     => Dec19x19!(0),
+///     (Dec19x19::MIN + Dec19x19::SMALLEST_STEP) => Dec19x19::MIN,
+///     (Dec19x19::MIN)               => FAIL,
+///     (Dec19x19::MAX)               => Dec19x19::MAX - Dec19x19::SMALLEST_STEP,
+/// });
+/// ```
+///
+/// Discarding the result is reported by the `unused_must_use` lint.
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use fixed_num::*;
+/// Dec19x19!(1).next_up();
+/// ```
+impl Dec19x19 {
+    /// ✅ The smallest representable value greater than `self`, the same as adding
+    /// [`Self::SMALLEST_STEP`]. Saturates at [`Self::MAX`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn next_up(self) -> Self {
+        Self { repr: self.repr.saturating_add(1) }
+    }
+
+    /// ✅ The greatest representable value smaller than `self`, the same as subtracting
+    /// [`Self::SMALLEST_STEP`]. Saturates at [`Self::MIN`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn next_down(self) -> Self {
+        Self { repr: self.repr.saturating_sub(1) }
+    }
+
+    /// ✅ The smallest representable value greater than `self`, or `None` if `self` is
+    /// [`Self::MAX`]. Unlike [`Self::next_up`], reaching the boundary is reported.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_increment(self) -> Option<Self> {
+        match self.repr.checked_add(1) {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
+    }
+
+    /// ✅ The greatest representable value smaller than `self`, or `None` if `self` is
+    /// [`Self::MIN`]. Unlike [`Self::next_down`], reaching the boundary is reported.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_decrement(self) -> Option<Self> {
+        match self.repr.checked_sub(1) {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
+    }
+
+    /// ✅ The signed number of [`Self::SMALLEST_STEP`]s between `self` and `other`, positive if
+    /// `self` is greater. The difference of values with opposite signs can exceed the range of
+    /// [`i128`] (e.g. between [`Self::MAX`] and [`Self::MIN`]), in which case the result saturates
+    /// at [`i128::MAX`] or [`i128::MIN`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19!(1).ulps_between(Dec19x19!(1)), 0);
+    /// assert_eq!(Dec19x19!(1).next_up().ulps_between(Dec19x19!(1)), 1);
+    /// assert_eq!(Dec19x19!(1).next_down().ulps_between(Dec19x19!(1)), -1);
+    /// assert_eq!(Dec19x19!(1).ulps_between(Dec19x19!(0.9)), 1_000_000_000_000_000_000);
+    /// assert_eq!(Dec19x19::MAX.ulps_between(Dec19x19!(0)), i128::MAX);
+    /// assert_eq!(Dec19x19::MAX.ulps_between(Dec19x19::MIN), i128::MAX);
+    /// assert_eq!(Dec19x19::MIN.ulps_between(Dec19x19::MAX), i128::MIN);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn ulps_between(self, other: Self) -> i128 {
+        self.repr.saturating_sub(other.repr)
+    }
+}
+
 // ==============
 // === Signum ===
 // ==============
@@ -419,6 +930,109 @@ impl Signum for Dec19x19 {
     }
 }}
 
+impl Dec19x19 {
+    /// The sign of the number as an `i8`: `1` if positive, `0` if zero, `-1` if negative.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19!(3.5).sign(), 1);
+    /// assert_eq!(Dec19x19!(0).sign(), 0);
+    /// assert_eq!(Dec19x19!(-3.5).sign(), -1);
+    /// assert_eq!(Dec19x19::SMALLEST_STEP.sign(), 1);
+    /// assert_eq!(Dec19x19::MIN.sign(), -1);
+    /// const SIGN: i8 = Dec19x19::MAX.sign();
+    /// assert_eq!(SIGN, 1);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn sign(self) -> i8 {
+        crate::i128_ops::sign_of_repr(self.repr)
+    }
+
+    /// Compares the value to zero directly on the representation, without constructing
+    /// `Dec19x19!(0)`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!(Dec19x19!(-3.5).cmp_zero(), Ordering::Less);
+    /// assert_eq!(Dec19x19!(0).cmp_zero(), Ordering::Equal);
+    /// assert_eq!(Dec19x19!(3.5).cmp_zero(), Ordering::Greater);
+    /// assert_eq!(Dec19x19::SMALLEST_STEP.cmp_zero(), Ordering::Greater);
+    /// assert_eq!(Dec19x19::MIN.cmp_zero(), Ordering::Less);
+    /// const ORD: Ordering = Dec19x19::MAX.cmp_zero();
+    /// assert_eq!(ORD, Ordering::Greater);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn cmp_zero(self) -> std::cmp::Ordering {
+        if self.repr < 0 {
+            std::cmp::Ordering::Less
+        } else if self.repr == 0 {
+            std::cmp::Ordering::Equal
+        } else {
+            std::cmp::Ordering::Greater
+        }
+    }
+
+    /// Like [`Signum::signum`], but returns `1.0` for zero, so the result is never zero. See the
+    /// zero convention described in [`Signum`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::signum_nonzero] {
+    ///     (Dec19x19::MAX)           => Dec19x19!(1.0),
+    ///     (Dec19x19!(3.0))          => Dec19x19!(1.0),
+    ///     (Dec19x19::SMALLEST_STEP) => Dec19x19!(1.0),
+    ///     (Dec19x19!(0.0))          => Dec19x19!(1.0),
+    ///     (Dec19x19!(-3.0))         => Dec19x19!(-1.0),
+    ///     (Dec19x19::MIN)           => Dec19x19!(-1.0),
+    /// });
+    /// // Zero conventions of the other sign helpers.
+    /// assert_eq!(Dec19x19!(0).signum(), Dec19x19!(0));
+    /// assert_eq!(Dec19x19!(0).signum_i128(), 0);
+    /// assert_eq!(Dec19x19!(0).sign(), 0);
+    /// assert_eq!(Dec19x19!(0).cmp_zero(), std::cmp::Ordering::Equal);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn signum_nonzero(self) -> Self {
+        if self.repr < 0 { Dec19x19!(-1) } else { Dec19x19!(1) }
+    }
+
+    /// ✅ Returns `a` if `cond_positive` is strictly positive and `b` otherwise, including when
+    /// `cond_positive` is zero. The selection is done without branching, by masking the `repr`
+    /// bits, which makes it suitable for numeric kernels that must avoid branches.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::select] {
+    ///     (Dec19x19!(2.5), Dec19x19!(1), Dec19x19!(-1))           => Dec19x19!(1),
+    ///     (Dec19x19::SMALLEST_STEP, Dec19x19::MAX, Dec19x19::MIN) => Dec19x19::MAX,
+    ///     (Dec19x19::MAX, Dec19x19!(1), Dec19x19!(-1))            => Dec19x19!(1),
+    ///     (Dec19x19!(0), Dec19x19!(1), Dec19x19!(-1))             => Dec19x19!(-1),
+    ///     (Dec19x19!(-2.5), Dec19x19!(1), Dec19x19!(-1))          => Dec19x19!(-1),
+    ///     (Dec19x19::MIN, Dec19x19::MAX, Dec19x19::MIN)           => Dec19x19::MIN,
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn select(cond_positive: Self, a: Self, b: Self) -> Self {
+        let mask = -((cond_positive.repr > 0) as i128);
+        Self { repr: b.repr ^ ((a.repr ^ b.repr) & mask) }
+    }
+}
+
 // ===========
 // === Neg ===
 // ===========
@@ -433,15 +1047,72 @@ impl Signum for Dec19x19 {
 ///     (Dec19x19!(3.0))  => Dec19x19!(-3.0),
 ///     (Dec19x19!(0.0))  => Dec19x19!(0.0),
 ///     (Dec19x19!(-3.0)) => Dec19x19!(3.0),
-///     (Dec19x19::MIN)   => Dec19x19::MAX,
 ///     ((Dec19x19::MIN + Dec19x19::SMALLEST_STEP)) => Dec19x19::MAX,
 /// });
 /// ```
+///
+/// By default, `-MIN` saturates to `MAX`. With the `strict_neg` feature, it panics instead.
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// if cfg!(feature = "strict_neg") {
+///     should_panic(|| -Dec19x19::MIN, "Overflow");
+///     should_panic(|| -&Dec19x19::MIN, "Overflow");
+/// } else {
+///     assert_eq!(-Dec19x19::MIN, Dec19x19::MAX);
+///     assert_eq!(-&Dec19x19::MIN, Dec19x19::MAX);
+/// }
+/// ```
 impl Neg for Dec19x19 {
     type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        #[cfg(feature = "strict_neg")]
+        { self.checked_neg().expect("Overflow: Cannot negate Dec19x19::MIN.") }
+        #[cfg(not(feature = "strict_neg"))]
+        { self.flip_sign() }
+    }
+}
+
+impl Neg for &Dec19x19 {
+    type Output = Dec19x19;
+    #[track_caller]
     #[inline(always)]
     fn neg(self) -> Self::Output {
-        if self == Self::MIN {
+        -*self
+    }
+}
+
+impl Dec19x19 {
+    /// Flips the sign of the value, the same as the `-` operator without the `strict_neg`
+    /// feature. The function is total: as `-MIN` is not representable, `MIN` is mapped to `MAX`,
+    /// and no input panics or overflows.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::flip_sign] {
+    ///     (Dec19x19!(3.5))  => Dec19x19!(-3.5),
+    ///     (Dec19x19!(-3.5)) => Dec19x19!(3.5),
+    ///     (Dec19x19!(0))    => Dec19x19!(0),
+    ///     (Dec19x19::MAX)   => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19::MIN)   => Dec19x19::MAX,
+    /// });
+    /// let values = [Dec19x19::MIN, Dec19x19!(-2.5), Dec19x19!(0), Dec19x19::MAX];
+    /// for t in values {
+    ///     assert_eq!(t.flip_sign(), t.checked_neg().unwrap_or(Dec19x19::MAX));
+    ///     assert_eq!(t.flip_sign(), t.saturating_neg());
+    ///     assert_eq!(t.flip_sign(), t.neg_const());
+    /// }
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn flip_sign(self) -> Self {
+        if self.repr == i128::MIN {
             Self::MAX
         } else {
             Self::from_repr(-self.repr)
@@ -449,17 +1120,85 @@ impl Neg for Dec19x19 {
     }
 }
 
-// ===========
-// === Abs ===
-// ===========
-
 const_impl!{
 /// # Tests
 ///
 /// ```
 /// # use fixed_num::*;
 /// # use validator::*;
-/// check!( [Dec19x19::abs] {
+/// check! ( [Dec19x19::checked_neg] {
+///     (Dec19x19!(3.5))  => Dec19x19!(-3.5),
+///     (Dec19x19!(-3.5)) => Dec19x19!(3.5),
+///     (Dec19x19!(0))    => Dec19x19!(0),
+///     (Dec19x19::MAX)   => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+///     ((Dec19x19::MIN + Dec19x19::SMALLEST_STEP)) => Dec19x19::MAX,
+///     (Dec19x19::MIN)   => FAIL,
+/// });
+/// ```
+impl CheckedNeg for Dec19x19 {
+    #[inline(always)]
+    #[allow(clippy::manual_map)]
+    fn checked_neg(self) -> Option<Self> {
+        match self.repr.checked_neg() {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
+    }
+}}
+
+const_impl!{
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::saturating_neg] {
+///     (Dec19x19!(3.5))  => Dec19x19!(-3.5),
+///     (Dec19x19!(-3.5)) => Dec19x19!(3.5),
+///     (Dec19x19!(0))    => Dec19x19!(0),
+///     (Dec19x19::MAX)   => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::MIN)   => Dec19x19::MAX,
+/// });
+/// ```
+impl SaturatingNeg for Dec19x19 {
+    #[inline(always)]
+    fn saturating_neg(self) -> Self {
+        Self { repr: self.repr.saturating_neg() }
+    }
+}}
+
+const_impl!{
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::wrapping_neg] {
+///     (Dec19x19!(3.5))  => Dec19x19!(-3.5),
+///     (Dec19x19!(-3.5)) => Dec19x19!(3.5),
+///     (Dec19x19!(0))    => Dec19x19!(0),
+///     (Dec19x19::MAX)   => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::MIN)   => Dec19x19::MIN,
+/// });
+/// ```
+impl WrappingNeg for Dec19x19 {
+    #[inline(always)]
+    fn wrapping_neg(self) -> Self {
+        Self { repr: self.repr.wrapping_neg() }
+    }
+}}
+
+// ===========
+// === Abs ===
+// ===========
+
+const_impl!{
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::abs] {
 ///     (Dec19x19::MAX)   => Dec19x19::MAX,
 ///     (Dec19x19!(3.0))  => Dec19x19!(3.0),
 ///     (Dec19x19!(0.0))  => Dec19x19!(0.0),
@@ -477,6 +1216,32 @@ impl Abs for Dec19x19 {
     }
 }}
 
+const_impl!{
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::checked_abs] {
+///     (Dec19x19::MAX)                            => Dec19x19::MAX,
+///     (Dec19x19!(3.0))                           => Dec19x19!(3.0),
+///     (Dec19x19!(0.0))                           => Dec19x19!(0.0),
+///     (Dec19x19!(-3.0))                          => Dec19x19!(3.0),
+///     (Dec19x19::MIN + Dec19x19::SMALLEST_STEP)  => Dec19x19::MAX,
+///     (Dec19x19::MIN)                            => FAIL,
+/// });
+/// ```
+impl CheckedAbs for Dec19x19 {
+    #[inline(always)]
+    #[allow(clippy::manual_map)]
+    fn checked_abs(self) -> Option<Self> {
+        match self.repr.checked_abs() {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
+    }
+}}
+
 // ===========
 // === Rem ===
 // ===========
@@ -512,6 +1277,261 @@ impl Rem for Dec19x19 {
     }
 }
 
+/// The remainder of the division by an integer. Every `i64` fits in [`Dec19x19`], so this is the
+/// same as the remainder of the division by `Dec19x19::from(rhs)`. The division is truncated, so
+/// the result has the sign of the dividend, like for the primitive integer types. The remainder of
+/// the division by zero is the dividend itself.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::rem] {
+///     (Dec19x19!(14.7), 5_i64)   => Dec19x19!(4.7),
+///     (Dec19x19!(15), 5_i64)     => Dec19x19!(0),
+///     (Dec19x19!(14.7), -5_i64)  => Dec19x19!(4.7),
+///     (Dec19x19!(-14.7), 5_i64)  => Dec19x19!(-4.7),
+///     (Dec19x19!(-14.7), -5_i64) => Dec19x19!(-4.7),
+///     (Dec19x19!(0.3), 1_i64)    => Dec19x19!(0.3),
+///     (Dec19x19!(14.7), 0_i64)   => Dec19x19!(14.7),
+///     (Dec19x19::MAX, 1_i64)     => Dec19x19!(0.168_730_371_588_410_572_7),
+///     (Dec19x19::MIN, -1_i64)    => Dec19x19!(-0.168_730_371_588_410_572_8),
+///     (Dec19x19::MAX, i64::MAX)
+///         => Dec19x19!(7_790_746_309_192_147_366.168_730_371_588_410_572_7),
+///     (Dec19x19::MIN, i64::MIN)
+///         => Dec19x19!(-7_790_746_309_192_147_365.168_730_371_588_410_572_8),
+/// });
+/// ```
+impl Rem<i64> for Dec19x19 {
+    type Output = Self;
+    #[inline(always)]
+    fn rem(self, rhs: i64) -> Self::Output {
+        self % Self::from_i64(rhs)
+    }
+}
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::is_multiple_of] {
+///     (Dec19x19!(0.15), Dec19x19!(0.05))          => true,
+///     (Dec19x19!(0.16), Dec19x19!(0.05))          => false,
+///     (Dec19x19!(-0.15), Dec19x19!(0.05))         => true,
+///     (Dec19x19!(0.15), Dec19x19!(-0.05))         => true,
+///     (Dec19x19!(0), Dec19x19!(0.05))             => true,
+///     (Dec19x19!(0), Dec19x19!(0))                => true,
+///     (Dec19x19!(0.15), Dec19x19!(0))             => false,
+///     (Dec19x19::MAX, Dec19x19::SMALLEST_STEP)    => true,
+///     (Dec19x19::MIN, -Dec19x19::SMALLEST_STEP)   => true,
+///     (Dec19x19::MAX, Dec19x19!(1))               => false,
+/// });
+/// ```
+impl Dec19x19 {
+    /// Checks whether `self` is an exact multiple of `divisor`. Zero is the only multiple of zero.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_multiple_of(self, divisor: Self) -> bool {
+        if divisor.repr == 0 {
+            self.is_zero()
+        } else if divisor.repr == -1 {
+            true
+        } else {
+            self.repr % divisor.repr == 0
+        }
+    }
+}
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::checked_div_euclid] {
+///     (Dec19x19!(7.5), Dec19x19!(2))              => Dec19x19!(3),
+///     (Dec19x19!(-7.5), Dec19x19!(2))             => Dec19x19!(-4),
+///     (Dec19x19!(7.5), Dec19x19!(-2))             => Dec19x19!(-3),
+///     (Dec19x19!(-7.5), Dec19x19!(-2))            => Dec19x19!(4),
+///     (Dec19x19!(1), Dec19x19!(0.3))              => Dec19x19!(3),
+///     (Dec19x19::MAX, Dec19x19!(1))               => Dec19x19::MAX_INT,
+///     (Dec19x19!(7.5), Dec19x19!(0))              => FAIL,
+///     (Dec19x19::MIN, -Dec19x19::SMALLEST_STEP)   => FAIL,
+///     (Dec19x19::MAX, Dec19x19::SMALLEST_STEP)    => FAIL,
+/// });
+///
+/// check!( [Dec19x19::checked_rem_euclid] {
+///     (Dec19x19!(7.5), Dec19x19!(2))              => Dec19x19!(1.5),
+///     (Dec19x19!(-7.5), Dec19x19!(2))             => Dec19x19!(0.5),
+///     (Dec19x19!(7.5), Dec19x19!(-2))             => Dec19x19!(1.5),
+///     (Dec19x19!(-7.5), Dec19x19!(-2))            => Dec19x19!(0.5),
+///     (Dec19x19!(1), Dec19x19!(0.3))              => Dec19x19!(0.1),
+///     (Dec19x19::MAX, Dec19x19::SMALLEST_STEP)    => Dec19x19!(0),
+///     (Dec19x19::MIN, Dec19x19::MAX)              => Dec19x19::MAX - Dec19x19::SMALLEST_STEP,
+///     (Dec19x19!(7.5), Dec19x19!(0))              => FAIL,
+///     (Dec19x19::MIN, -Dec19x19::SMALLEST_STEP)   => FAIL,
+/// });
+///
+/// // The quotient and remainder reconstruct the dividend.
+/// for (a, b) in [(Dec19x19!(-7.5), Dec19x19!(2)), (Dec19x19!(3.25), Dec19x19!(-0.7))] {
+///     let q = a.checked_div_euclid(b).unwrap();
+///     let r = a.checked_rem_euclid(b).unwrap();
+///     assert_eq!(q * b + r, a);
+///     assert!(r >= Dec19x19!(0) && r < b.abs());
+/// }
+/// ```
+impl Dec19x19 {
+    /// Euclidean division, returning the integer `q` such that `self = q * rhs + r`, where
+    /// `0 <= r < |rhs|`. Returns `None` if `rhs` is zero or the quotient overflows, the same as
+    /// [`i128::checked_div_euclid`].
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        match self.repr.checked_div_euclid(rhs.repr) {
+            Some(q) => match q.checked_mul(FRAC_SCALE_I128) {
+                Some(repr) => Some(Self { repr }),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Euclidean remainder, the non-negative `r` such that `self = q * rhs + r` for an integer
+    /// `q`. Returns `None` if `rhs` is zero or the matching quotient overflows, the same as
+    /// [`i128::checked_rem_euclid`].
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    #[allow(clippy::manual_map)]
+    pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        match self.repr.checked_rem_euclid(rhs.repr) {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
+    }
+
+    /// The integer quotient of `self / rhs`, i.e. how many times `rhs` fits in `self`, truncated
+    /// toward zero like the division of primitive integers. Computed directly on the
+    /// representations, without a full-precision division. Returns `None` if `rhs` is zero or the
+    /// quotient does not fit in [`i128`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::int_div] {
+    ///     (Dec19x19!(20), Dec19x19!(3)) => 6,
+    ///     (Dec19x19!(-20), Dec19x19!(3)) => -6,
+    ///     (Dec19x19!(20), Dec19x19!(-3)) => -6,
+    ///     (Dec19x19!(-20), Dec19x19!(-3)) => 6,
+    ///     (Dec19x19!(2), Dec19x19!(3)) => 0,
+    ///     (Dec19x19!(-2), Dec19x19!(3)) => 0,
+    ///     (Dec19x19!(10), Dec19x19!(0.25)) => 40,
+    ///     (Dec19x19!(1), Dec19x19::SMALLEST_STEP) => 10_000_000_000_000_000_000,
+    ///     (Dec19x19::MAX, Dec19x19::SMALLEST_STEP) => i128::MAX,
+    ///     (Dec19x19::MIN, Dec19x19::SMALLEST_STEP) => i128::MIN,
+    ///     (Dec19x19::MIN, Dec19x19::MIN) => 1,
+    ///     (Dec19x19!(20), Dec19x19!(0)) => FAIL,
+    ///     (Dec19x19::MIN, -Dec19x19::SMALLEST_STEP) => FAIL,
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn int_div(self, rhs: Self) -> Option<i128> {
+        self.repr.checked_div(rhs.repr)
+    }
+
+    /// ✅ The remainder `r` and the quotient `q` such that `self = q * rhs + r`, like C's
+    /// `remquo`, useful for the argument reduction of periodic functions. The quotient is the
+    /// exact `self / rhs` rounded to the nearest integer, with ties to even, so `|r| <= |rhs| / 2`.
+    ///
+    /// If `rhs` is zero, the result is `(self, 0)`, matching `self % rhs`. If the quotient does not
+    /// fit in [`i64`], it saturates and the remainder is not fully reduced, but the equality above
+    /// still holds.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::remquo] {
+    ///     (Dec19x19!(10), Dec19x19!(3))    => (Dec19x19!(1), 3),
+    ///     (Dec19x19!(11), Dec19x19!(3))    => (Dec19x19!(-1), 4),
+    ///     (Dec19x19!(-11), Dec19x19!(3))   => (Dec19x19!(1), -4),
+    ///     (Dec19x19!(11), Dec19x19!(-3))   => (Dec19x19!(-1), -4),
+    ///     (Dec19x19!(7.5), Dec19x19!(5))   => (Dec19x19!(-2.5), 2),
+    ///     (Dec19x19!(12.5), Dec19x19!(5))  => (Dec19x19!(2.5), 2),
+    ///     (Dec19x19!(-12.5), Dec19x19!(5)) => (Dec19x19!(-2.5), -2),
+    ///     (Dec19x19!(10), Dec19x19!(0.75)) => (Dec19x19!(0.25), 13),
+    ///     (Dec19x19!(2), Dec19x19!(10))    => (Dec19x19!(2), 0),
+    ///     (Dec19x19!(10), Dec19x19!(0))    => (Dec19x19!(10), 0),
+    ///     (Dec19x19::MAX, Dec19x19::MAX)   => (Dec19x19!(0), 1),
+    ///     (Dec19x19::MIN, Dec19x19::MAX)   => (-Dec19x19::SMALLEST_STEP, -1),
+    /// });
+    ///
+    /// let two_pi = Dec19x19!(6.283_185_307_179_586_476_9);
+    /// assert_eq!(Dec19x19!(10).remquo(two_pi), (Dec19x19!(10) - two_pi * Dec19x19!(2), 2));
+    ///
+    /// let step = Dec19x19::SMALLEST_STEP;
+    /// let cases = [
+    ///     (Dec19x19!(10), two_pi),
+    ///     (Dec19x19!(-1234.5678), Dec19x19!(0.001)),
+    ///     (Dec19x19::MAX, Dec19x19::MAX / Dec19x19!(1.5)),
+    ///     (Dec19x19::MIN, Dec19x19::MAX / Dec19x19!(1.5)),
+    ///     (Dec19x19::MAX, Dec19x19!(-3)),
+    ///     (Dec19x19::MAX, step),
+    ///     (Dec19x19::MIN, step),
+    ///     (Dec19x19::MIN, -step),
+    ///     (Dec19x19!(1), Dec19x19!(1e-17)),
+    /// ];
+    /// for (lhs, rhs) in cases {
+    ///     let (rem, quot) = lhs.remquo(rhs);
+    ///     assert_eq!(Dec19x19::from(quot).checked_mul_add(rhs, rem), Some(lhs));
+    ///     if quot != i64::MAX && quot != i64::MIN {
+    ///         assert!(rem.abs() <= rhs.abs() / Dec19x19!(2));
+    ///     }
+    /// }
+    /// assert_eq!(Dec19x19::MAX.remquo(step).1, i64::MAX);
+    /// assert_eq!(Dec19x19::MIN.remquo(step).1, i64::MIN);
+    /// assert_eq!(Dec19x19::MIN.remquo(-step).1, i64::MAX);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn remquo(self, rhs: Self) -> (Self, i64) {
+        if rhs.repr == 0 {
+            return (self, 0);
+        }
+        let quot = match self.repr.checked_div(rhs.repr) {
+            Some(quot) => {
+                // `|rem| < |rhs| <= 2^127`, so doubling it fits in `u128`.
+                let twice_rem = (self.repr % rhs.repr).unsigned_abs() * 2;
+                let divisor = rhs.repr.unsigned_abs();
+                if twice_rem > divisor || (twice_rem == divisor && quot % 2 != 0) {
+                    if (self.repr < 0) == (rhs.repr < 0) { quot + 1 } else { quot - 1 }
+                } else {
+                    quot
+                }
+            }
+            // Only `MIN / -SMALLEST_STEP` overflows, and its quotient is positive.
+            None => i128::MAX,
+        };
+        let quot = if quot > i64::MAX as i128 {
+            i64::MAX
+        } else if quot < i64::MIN as i128 {
+            i64::MIN
+        } else {
+            quot as i64
+        };
+        // The exact remainder always fits in `i128`, so the wrapping operations are lossless.
+        let repr = self.repr.wrapping_sub((quot as i128).wrapping_mul(rhs.repr));
+        (Self { repr }, quot)
+    }
+}
+
 // ===========
 // === Add ===
 // ===========
@@ -541,12 +1561,45 @@ impl Rem for Dec19x19 {
 ///     |(f1, b1), (f2, b2)| should_eq(f1 + f2, b1 + b2)
 /// );
 /// ```
+///
+/// # Debug Checked Ops
+///
+/// With the `debug_checked_ops` feature and overflow checks enabled, `+`, `-`, `*`, and `/`
+/// panic with a message naming the operands, for both [`Dec19x19`] and [`i64`] right-hand sides.
+///
+/// ```
+/// # use fixed_num::*;
+/// fn panic_msg(f: impl FnOnce() -> Dec19x19 + std::panic::UnwindSafe) -> Option<String> {
+///     let err = std::panic::catch_unwind(f).err()?;
+///     err.downcast_ref::<String>().cloned()
+/// }
+/// if cfg!(all(feature = "debug_checked_ops", inherit_overflow_checks)) {
+///     let max = Dec19x19::MAX;
+///     let one = Dec19x19!(1);
+///     let zero = Dec19x19!(0);
+///     let msg = |op: &str, rhs: Dec19x19|
+///         format!("Overflow in Dec19x19 {op}: lhs = {max}, rhs = {rhs}");
+///     assert_eq!(panic_msg(|| max + one), Some(msg("add", one)));
+///     assert_eq!(panic_msg(|| max - -one), Some(msg("sub", -one)));
+///     assert_eq!(panic_msg(|| max * Dec19x19!(2)), Some(msg("mul", Dec19x19!(2))));
+///     assert_eq!(panic_msg(|| max / zero), Some(msg("div", zero)));
+///     assert_eq!(panic_msg(|| max - one), None);
+///     assert_eq!(panic_msg(|| max + 1_i64), Some(msg("add", one)));
+///     assert_eq!(panic_msg(|| max - -1_i64), Some(msg("sub", -one)));
+///     assert_eq!(panic_msg(|| max * 2_i64), Some(msg("mul", Dec19x19!(2))));
+///     assert_eq!(panic_msg(|| max / 0_i64), Some(msg("div", zero)));
+///     assert_eq!(panic_msg(|| max - 1_i64), None);
+/// }
+/// ```
 impl Add for Dec19x19 {
     type Output = Self;
     #[track_caller]
     #[inline(always)]
     fn add(self, rhs: Self) -> Self {
-        self.unchecked_add(rhs)
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_add(rhs).unwrap_or_else(|| op_overflow("add", self, rhs)) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_add(rhs) }
     }
 }
 
@@ -563,6 +1616,7 @@ const_impl!{ impl CheckedAdd for Dec19x19 {
     type Output = Self;
     #[track_caller]
     #[inline(always)]
+    #[allow(clippy::manual_map)]
     fn checked_add(self, rhs: Self) -> Option<Self> {
         if let Some(result) = self.repr.checked_add(rhs.repr) {
             Some(Self::from_repr(result))
@@ -608,6 +1662,56 @@ impl AddAssign for Dec19x19 {
 
 impl_op_for_refs!(Add::add);
 
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::add, Dec19x19::checked_add] {
+///     (Dec19x19!(1.5), 2_i64) => Dec19x19!(3.5),
+///     (Dec19x19!(1.5), -2_i64) => Dec19x19!(-0.5),
+///     (Dec19x19!(0), i64::MAX) => Dec19x19!(9_223_372_036_854_775_807),
+///     (Dec19x19!(0), i64::MIN) => Dec19x19!(-9_223_372_036_854_775_808),
+///     (Dec19x19::MAX, 1_i64) => FAIL,
+///     (Dec19x19::MIN, -1_i64) => FAIL,
+///     (Dec19x19::MAX_INT, i64::MAX) => FAIL,
+/// });
+/// ```
+impl Add<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn add(self, rhs: i64) -> Self {
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_add(rhs).unwrap_or_else(|| op_overflow("add", self, Self::from_i64(rhs))) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_add(rhs) }
+    }
+}
+
+const_impl!{ impl UncheckedAdd<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_add(self, rhs: i64) -> Self {
+        Self::from_repr(self.repr + Self::from_i64(rhs).repr)
+    }
+}}
+
+const_impl!{ impl CheckedAdd<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    #[allow(clippy::manual_map)]
+    fn checked_add(self, rhs: i64) -> Option<Self> {
+        if let Some(result) = self.repr.checked_add(Self::from_i64(rhs).repr) {
+            Some(Self::from_repr(result))
+        } else {
+            None
+        }
+    }
+}}
+
 // ===========
 // === Sub ===
 // ===========
@@ -640,7 +1744,10 @@ impl Sub for Dec19x19 {
     #[track_caller]
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self {
-        self.unchecked_sub(rhs)
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_sub(rhs).unwrap_or_else(|| op_overflow("sub", self, rhs)) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_sub(rhs) }
     }
 }
 
@@ -657,6 +1764,7 @@ const_impl!{ impl CheckedSub for Dec19x19 {
     type Output = Self;
     #[track_caller]
     #[inline(always)]
+    #[allow(clippy::manual_map)]
     fn checked_sub(self, rhs: Self) -> Option<Self> {
         if let Some(result) = self.repr.checked_sub(rhs.repr) {
             Some(Self::from_repr(result))
@@ -703,6 +1811,55 @@ impl SubAssign for Dec19x19 {
 
 impl_op_for_refs!(Sub::sub);
 
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::sub, Dec19x19::checked_sub] {
+///     (Dec19x19!(1.5), 2_i64) => Dec19x19!(-0.5),
+///     (Dec19x19!(1.5), -2_i64) => Dec19x19!(3.5),
+///     (Dec19x19!(0), i64::MAX) => Dec19x19!(-9_223_372_036_854_775_807),
+///     (Dec19x19::MIN, 1_i64) => FAIL,
+///     (Dec19x19::MAX, -1_i64) => FAIL,
+///     (Dec19x19::MIN_INT, i64::MAX) => FAIL,
+/// });
+/// ```
+impl Sub<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn sub(self, rhs: i64) -> Self {
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_sub(rhs).unwrap_or_else(|| op_overflow("sub", self, Self::from_i64(rhs))) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_sub(rhs) }
+    }
+}
+
+const_impl!{ impl UncheckedSub<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_sub(self, rhs: i64) -> Self {
+        Self::from_repr(self.repr - Self::from_i64(rhs).repr)
+    }
+}}
+
+const_impl!{ impl CheckedSub<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    #[allow(clippy::manual_map)]
+    fn checked_sub(self, rhs: i64) -> Option<Self> {
+        if let Some(result) = self.repr.checked_sub(Self::from_i64(rhs).repr) {
+            Some(Self::from_repr(result))
+        } else {
+            None
+        }
+    }
+}}
+
 // ==========
 // === Mul ==
 // ==========
@@ -752,7 +1909,10 @@ impl Mul for Dec19x19 {
     #[track_caller]
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self {
-        self.unchecked_mul(rhs)
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_mul(rhs).unwrap_or_else(|| op_overflow("mul", self, rhs)) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_mul(rhs) }
     }
 }
 
@@ -762,7 +1922,8 @@ impl Dec19x19 {
     /// instead.
     #[track_caller]
     #[inline(always)]
-    pub fn unchecked_mul_no_opt(self, rhs: Self) -> Self {
+    #[must_use]
+    pub const fn unchecked_mul_no_opt(self, rhs: Self) -> Self {
         // 1) sign & magnitudes
         let neg = (self.repr < 0) ^ (rhs.repr < 0);
         let ua  = self.repr.unsigned_abs();
@@ -781,7 +1942,8 @@ impl Dec19x19 {
 
         // 4) reassemble
         let mag = int * FRAC_SCALE_U128 + cross + frac;
-        let mut repr: i128 = mag.try_into().expect("Overflow");
+        assert!(mag <= i128::MAX as u128, "Overflow");
+        let mut repr = mag as i128;
         if neg { repr = -repr; }
         Self { repr }
     }
@@ -791,6 +1953,7 @@ impl Dec19x19 {
     /// (default).
     #[track_caller]
     #[inline(always)]
+    #[must_use]
     pub fn unchecked_mul_opt(self, rhs: Self) -> Self {
         // 1) sign & magnitudes
         let neg = (self.repr < 0) ^ (rhs.repr < 0);
@@ -834,6 +1997,7 @@ impl Dec19x19 {
     /// probably want to use `Dec19x19::checked_mul` with `mul_opt` flag disabled instead.
     #[track_caller]
     #[inline(always)]
+    #[must_use]
     pub fn checked_mul_no_opt(self, rhs: Self) -> Option<Self> {
         // 1) sign & magnitudes
         let neg = (self.repr < 0) ^ (rhs.repr < 0);
@@ -867,6 +2031,7 @@ impl Dec19x19 {
     /// probably want to use `Dec19x19::checked_mul` with `mul_opt` flag enabled instead.
     #[track_caller]
     #[inline(always)]
+    #[must_use]
     pub fn checked_mul_opt(self, rhs: Self) -> Option<Self> {
         // 1) sign & magnitudes
         let neg = (self.repr < 0) ^ (rhs.repr < 0);
@@ -966,28 +2131,171 @@ impl MulAssign for Dec19x19 {
 
 impl_op_for_refs!(Mul::mul);
 
-// ===========
-// === Div ===
-// ===========
+/// Reason a [`Dec19x19::mul_overflowing`] call failed, naming the stage of the multiplication
+/// that went out of bounds.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MulOverflow {
+    /// The product of the integer parts alone does not fit.
+    IntPart,
+    /// The integer product fits, but adding the cross terms (integer × fraction) does not.
+    CrossTerm,
+    /// Everything but the fraction × fraction term fits, but the assembled magnitude does not
+    /// narrow to `i128`.
+    Narrowing,
+}
+
+impl std::error::Error for MulOverflow {}
+impl std::fmt::Display for MulOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IntPart => write!(f, "Multiplication overflow in the integer part product"),
+            Self::CrossTerm => write!(f, "Multiplication overflow in the cross term"),
+            Self::Narrowing => write!(f, "Multiplication overflow when narrowing the result"),
+        }
+    }
+}
+
+impl Dec19x19 {
+    /// Multiplication reporting which stage overflowed. Succeeds exactly when the product is
+    /// representable, including a result of `Dec19x19::MIN`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::MulOverflow;
+    /// assert_eq!(Dec19x19!(1.5).mul_overflowing(Dec19x19!(-2)), Ok(Dec19x19!(-3)));
+    /// assert_eq!(Dec19x19::MAX.mul_overflowing(Dec19x19!(1)), Ok(Dec19x19::MAX));
+    /// assert_eq!(Dec19x19::MIN.mul_overflowing(Dec19x19!(1)), Ok(Dec19x19::MIN));
+    /// assert_eq!(Dec19x19::MAX.mul_overflowing(Dec19x19!(-1)), Ok(-Dec19x19::MAX));
+    /// assert_eq!(
+    ///     Dec19x19!(1e10).mul_overflowing(Dec19x19!(1e10)),
+    ///     Err(MulOverflow::IntPart)
+    /// );
+    /// assert_eq!(Dec19x19::MAX_INT.mul_overflowing(Dec19x19!(2)), Err(MulOverflow::IntPart));
+    /// assert_eq!(Dec19x19::MIN.mul_overflowing(Dec19x19!(-1)), Err(MulOverflow::CrossTerm));
+    /// assert_eq!(Dec19x19::MAX_INT.mul_overflowing(Dec19x19!(1.5)), Err(MulOverflow::CrossTerm));
+    /// assert_eq!(
+    ///     Dec19x19::MAX_INT.mul_overflowing(Dec19x19!(-1.5)),
+    ///     Err(MulOverflow::CrossTerm)
+    /// );
+    /// let a = Dec19x19::from_repr(70_780_489_790_179_468_729_821_236_854);
+    /// let b = Dec19x19::from_repr(24_037_864_666_496_796_677_190_132_057);
+    /// assert_eq!(a.mul_overflowing(b), Err(MulOverflow::Narrowing));
+    /// assert_eq!(a.checked_mul(b), None);
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    pub const fn mul_overflowing(self, rhs: Self) -> Result<Self, MulOverflow> {
+        let neg = (self.repr < 0) ^ (rhs.repr < 0);
+        let ua  = self.repr.unsigned_abs();
+        let ub  = rhs.repr.unsigned_abs();
+        let limit = if neg { i128::MIN.unsigned_abs() } else { i128::MAX as u128 };
+
+        let ai = ua / FRAC_SCALE_U128;
+        let af = ua % FRAC_SCALE_U128;
+        let bi = ub / FRAC_SCALE_U128;
+        let bf = ub % FRAC_SCALE_U128;
+
+        // Both integer parts are below 2^64, so `ai * bi` itself cannot overflow.
+        let int = match (ai * bi).checked_mul(FRAC_SCALE_U128) {
+            Some(int) if int <= limit => int,
+            _ => return Err(MulOverflow::IntPart),
+        };
+        // Both products are below 2^64 * 10^19 < 2^128, the sums may overflow.
+        let with_cross = match int.checked_add(ai * bf) {
+            Some(t) => t.checked_add(bi * af),
+            None => None,
+        };
+        let with_cross = match with_cross {
+            Some(t) if t <= limit => t,
+            _ => return Err(MulOverflow::CrossTerm),
+        };
+        // `with_cross <= limit < 2^127` and `frac < 10^19`, so the sum cannot overflow `u128`.
+        let mag = with_cross + af * bf / FRAC_SCALE_U128;
+        if mag > limit {
+            return Err(MulOverflow::Narrowing);
+        }
+        let repr = if neg { (mag as i128).wrapping_neg() } else { mag as i128 };
+        Ok(Self { repr })
+    }
+}
 
+/// Multiplication by an integer scales the representation directly, without splitting it into
+/// the integer and fractional parts.
+///
 /// # Tests
 ///
 /// ```
 /// # use fixed_num::*;
 /// # use validator::*;
-/// check! ( [Dec19x19::div, Dec19x19::checked_div] {
-///     (Dec19x19!(20), Dec19x19!(0.2)) => Dec19x19!(100.0),
-///     (Dec19x19::MAX, Dec19x19!(-1)) => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
-///     (Dec19x19::MIN + Dec19x19::SMALLEST_STEP, Dec19x19!(-1)) => Dec19x19::MAX,
-///     (Dec19x19::MAX - Dec19x19!(10), Dec19x19!(0.1)) => FAIL,
-///     (Dec19x19::MAX - Dec19x19!(10), Dec19x19!(0)) => FAIL,
-///     (Dec19x19!(10), Dec19x19!(0)) => FAIL,
-///     (Dec19x19::MAX, Dec19x19!(0.1)) => FAIL,
+/// check!( [Dec19x19::mul, Dec19x19::checked_mul] {
+///     (Dec19x19!(1.5), 2_i64) => Dec19x19!(3.0),
+///     (Dec19x19!(1.5), -3_i64) => Dec19x19!(-4.5),
+///     (Dec19x19::SMALLEST_STEP, 7_i64) => Dec19x19!(0.000_000_000_000_000_000_7),
+///     (Dec19x19!(1), i64::MAX) => Dec19x19!(9_223_372_036_854_775_807),
+///     (Dec19x19!(2), i64::MAX) => FAIL,
+///     (Dec19x19::MAX, 2_i64) => FAIL,
+///     (Dec19x19::MIN, -1_i64) => FAIL,
 /// });
 /// ```
-///
-/// # Fuzzy
-///
+impl Mul<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn mul(self, rhs: i64) -> Self {
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_mul(rhs).unwrap_or_else(|| op_overflow("mul", self, Self::from_i64(rhs))) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_mul(rhs) }
+    }
+}
+
+const_impl!{ impl UncheckedMul<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_mul(self, rhs: i64) -> Self {
+        Self::from_repr(self.repr * rhs as i128)
+    }
+}}
+
+const_impl!{ impl CheckedMul<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    #[allow(clippy::manual_map)]
+    fn checked_mul(self, rhs: i64) -> Option<Self> {
+        if let Some(result) = self.repr.checked_mul(rhs as i128) {
+            Some(Self::from_repr(result))
+        } else {
+            None
+        }
+    }
+}}
+
+// ===========
+// === Div ===
+// ===========
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::div, Dec19x19::checked_div] {
+///     (Dec19x19!(20), Dec19x19!(0.2)) => Dec19x19!(100.0),
+///     (Dec19x19::MAX, Dec19x19!(-1)) => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::MIN + Dec19x19::SMALLEST_STEP, Dec19x19!(-1)) => Dec19x19::MAX,
+///     (Dec19x19::MAX - Dec19x19!(10), Dec19x19!(0.1)) => FAIL,
+///     (Dec19x19::MAX - Dec19x19!(10), Dec19x19!(0)) => FAIL,
+///     (Dec19x19!(10), Dec19x19!(0)) => FAIL,
+///     (Dec19x19::MAX, Dec19x19!(0.1)) => FAIL,
+/// });
+/// ```
+///
+/// # Fuzzy
+///
 /// ```
 /// # use fixed_num::*;
 /// # use validator::*;
@@ -1000,7 +2308,10 @@ impl Div for Dec19x19 {
     #[track_caller]
     #[inline(always)]
     fn div(self, rhs: Self) -> Self {
-        self.unchecked_div(rhs)
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_div(rhs).unwrap_or_else(|| op_overflow("div", self, rhs)) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_div(rhs) }
     }
 }
 
@@ -1061,6 +2372,278 @@ impl DivAssign for Dec19x19 {
 
 impl_op_for_refs!(Div::div);
 
+impl Dec19x19 {
+    /// Computes `self * mul / div` with the product kept in `i256`, so the result is returned
+    /// whenever it fits, even if `self * mul` alone would overflow. The result is truncated once,
+    /// so it can be more precise than the two operations done separately. Returns `None` if `div`
+    /// is zero or the result does not fit.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// // The product overflows, but the final result fits.
+    /// assert_eq!(Dec19x19::MAX.checked_mul(Dec19x19!(3)), None);
+    /// check! ( [Dec19x19::mul_div] {
+    ///     (Dec19x19!(10), Dec19x19!(3), Dec19x19!(2)) => Dec19x19!(15),
+    ///     (Dec19x19!(-10), Dec19x19!(3), Dec19x19!(4)) => Dec19x19!(-7.5),
+    ///     (Dec19x19!(1), Dec19x19!(1), Dec19x19!(3)) => Dec19x19!(0.333_333_333_333_333_333_3),
+    ///     (Dec19x19!(1), Dec19x19!(2), Dec19x19!(3)) => Dec19x19!(0.666_666_666_666_666_666_6),
+    ///     (Dec19x19::MAX, Dec19x19!(3), Dec19x19!(3)) => Dec19x19::MAX,
+    ///     (Dec19x19::MIN, Dec19x19!(7), Dec19x19!(7)) => Dec19x19::MIN,
+    ///     (Dec19x19::MAX, Dec19x19::MAX, Dec19x19::MAX) => Dec19x19::MAX,
+    ///     (Dec19x19!(1e18), Dec19x19!(1e18), Dec19x19!(1e17)) => Dec19x19!(1e19),
+    ///     (Dec19x19::SMALLEST_STEP, Dec19x19::SMALLEST_STEP, Dec19x19::SMALLEST_STEP)
+    ///         => Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19!(1), Dec19x19!(1), Dec19x19!(0)) => FAIL,
+    ///     (Dec19x19::MAX, Dec19x19!(2), Dec19x19!(1)) => FAIL,
+    ///     (Dec19x19::MIN, Dec19x19!(-1), Dec19x19!(1)) => FAIL,
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_div(self, mul: Self, div: Self) -> Option<Self> {
+        // `(a / S) * (b / S) / (c / S) * S = a * b / c`, where `S` is the fractional scale.
+        let product = i256_from_i128(self.repr) * i256_from_i128(mul.repr);
+        let result = product.checked_div(i256_from_i128(div.repr))?;
+        i256_to_i128(result).map(Self::from_repr)
+    }
+
+    /// Computes `self * mul + add` with the whole expression evaluated in `i256` and truncated
+    /// toward zero once, at the end. Returns `None` if the result does not fit, but not if only
+    /// the intermediate `self * mul` would overflow.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// let step = Dec19x19::SMALLEST_STEP;
+    /// // Truncating the product first would give `step`.
+    /// assert_eq!(step * Dec19x19!(-0.5) + step, step);
+    /// check! ( [Dec19x19::checked_mul_add] {
+    ///     (Dec19x19!(2), Dec19x19!(3), Dec19x19!(4)) => Dec19x19!(10),
+    ///     (Dec19x19!(-1.5), Dec19x19!(2), Dec19x19!(1)) => Dec19x19!(-2),
+    ///     (Dec19x19!(1), Dec19x19!(3), -step) => Dec19x19!(3) - step,
+    ///     (step, Dec19x19!(-0.5), step) => Dec19x19!(0),
+    ///     (step, Dec19x19!(0.5), step) => step,
+    ///     (Dec19x19::MAX, Dec19x19!(2), -Dec19x19::MAX) => Dec19x19::MAX,
+    ///     (Dec19x19::MIN + step, Dec19x19!(2), Dec19x19::MAX) => Dec19x19::MIN + step,
+    ///     (Dec19x19::MIN, Dec19x19!(2), Dec19x19::MAX) => FAIL,
+    ///     (Dec19x19::MAX, Dec19x19!(1), Dec19x19!(0)) => Dec19x19::MAX,
+    ///     (Dec19x19::MAX - step, Dec19x19!(1), step) => Dec19x19::MAX,
+    ///     (Dec19x19::MIN + step, Dec19x19!(1), -step) => Dec19x19::MIN,
+    ///     (Dec19x19::MAX, Dec19x19!(1), step) => FAIL,
+    ///     (Dec19x19::MIN, Dec19x19!(1), -step) => FAIL,
+    ///     (Dec19x19::MAX, Dec19x19!(2), Dec19x19!(0)) => FAIL,
+    ///     (Dec19x19::MAX, Dec19x19::MAX, Dec19x19::MIN) => FAIL,
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_mul_add(self, mul: Self, add: Self) -> Option<Self> {
+        // `((a / S) * (b / S) + c / S) * S = (a * b + c * S) / S`, where `S` is the fractional
+        // scale. Both terms are below `2^254`, so their sum fits in `i256`.
+        let product = i256_from_i128(self.repr) * i256_from_i128(mul.repr);
+        let sum = product + i256_from_i128(add.repr) * FRAC_SCALE_I256;
+        i256_to_i128(sum / FRAC_SCALE_I256).map(Self::from_repr)
+    }
+}
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use fixed_num::dec19x19::RoundingMode::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::div_rounded] {
+///     (Dec19x19!(1), Dec19x19!(3), Trunc)   => Dec19x19!(0.333_333_333_333_333_333_3),
+///     (Dec19x19!(2), Dec19x19!(3), Trunc)   => Dec19x19!(0.666_666_666_666_666_666_6),
+///     (Dec19x19!(2), Dec19x19!(3), Round)   => Dec19x19!(0.666_666_666_666_666_666_7),
+///     (Dec19x19!(1), Dec19x19!(3), Round)   => Dec19x19!(0.333_333_333_333_333_333_3),
+///     (Dec19x19!(1), Dec19x19!(3), Ceil)    => Dec19x19!(0.333_333_333_333_333_333_4),
+///     (Dec19x19!(1), Dec19x19!(3), Floor)   => Dec19x19!(0.333_333_333_333_333_333_3),
+///
+///     (Dec19x19!(-2), Dec19x19!(3), Trunc)  => Dec19x19!(-0.666_666_666_666_666_666_6),
+///     (Dec19x19!(-2), Dec19x19!(3), Round)  => Dec19x19!(-0.666_666_666_666_666_666_7),
+///     (Dec19x19!(2), Dec19x19!(-3), Floor)  => Dec19x19!(-0.666_666_666_666_666_666_7),
+///     (Dec19x19!(-2), Dec19x19!(-3), Floor) => Dec19x19!(0.666_666_666_666_666_666_6),
+///     (Dec19x19!(-2), Dec19x19!(3), Ceil)   => Dec19x19!(-0.666_666_666_666_666_666_6),
+///     (Dec19x19!(-2), Dec19x19!(-3), Ceil)  => Dec19x19!(0.666_666_666_666_666_666_7),
+///
+///     // Ties are rounded away from zero.
+///     (Dec19x19::SMALLEST_STEP, Dec19x19!(2), Round)  => Dec19x19::SMALLEST_STEP,
+///     (-Dec19x19::SMALLEST_STEP, Dec19x19!(2), Round) => -Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::SMALLEST_STEP, Dec19x19!(2), Trunc)  => Dec19x19!(0),
+///
+///     // Exact results are not changed.
+///     (Dec19x19!(1), Dec19x19!(4), Ceil)    => Dec19x19!(0.25),
+///     (Dec19x19!(-1), Dec19x19!(4), Floor)  => Dec19x19!(-0.25),
+///
+///     (Dec19x19!(1), Dec19x19!(0), Round)   => FAIL,
+///     (Dec19x19::MAX, Dec19x19!(0.5), Round) => FAIL,
+///     (Dec19x19::MAX, Dec19x19!(1), Ceil)   => Dec19x19::MAX,
+/// });
+/// ```
+impl Dec19x19 {
+    /// ✅ Division rounding the result to the nearest representable value using the given mode,
+    /// instead of truncating it like [`Div`] does. Returns `None` on division by zero or overflow.
+    #[inline(always)]
+    #[must_use]
+    pub fn div_rounded(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        let zero = i256_from_i128(0);
+        let one = i256_from_i128(1);
+        let rhs_i256 = i256_from_i128(rhs.repr);
+        if rhs_i256 == zero {
+            return None;
+        }
+        let scaled_lhs = i256_from_i128(self.repr) * FRAC_SCALE_I256;
+        let quot = scaled_lhs / rhs_i256;
+        let rem = scaled_lhs % rhs_i256;
+        let is_negative = (scaled_lhs < zero) != (rhs_i256 < zero);
+        let away_from_zero = rem != zero && match mode {
+            RoundingMode::Trunc => false,
+            RoundingMode::Floor => is_negative,
+            RoundingMode::Ceil => !is_negative,
+            RoundingMode::Round => rem.wrapping_abs() * I256_TWO >= rhs_i256.wrapping_abs(),
+        };
+        let result = match (away_from_zero, is_negative) {
+            (false, _) => quot,
+            (true, false) => quot + one,
+            (true, true) => quot - one,
+        };
+        i256_to_i128(result).map(Self::from_repr)
+    }
+}
+
+/// Division by an integer divides the representation directly, truncating toward zero the same
+/// way as the division by a `Dec19x19` does.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check!( [Dec19x19::div, Dec19x19::checked_div] {
+///     (Dec19x19!(3), 2_i64) => Dec19x19!(1.5),
+///     (Dec19x19!(1), 3_i64) => Dec19x19!(0.333_333_333_333_333_333_3),
+///     (Dec19x19!(-1), 3_i64) => Dec19x19!(-0.333_333_333_333_333_333_3),
+///     (Dec19x19::MAX, i64::MAX) => Dec19x19!(1.844_674_407_370_955_161_8),
+///     (Dec19x19::MAX, -1_i64) => Dec19x19::MIN + Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::MIN, -1_i64) => FAIL,
+///     (Dec19x19!(10), 0_i64) => FAIL,
+/// });
+/// ```
+impl Div<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn div(self, rhs: i64) -> Self {
+        #[cfg(all(feature = "debug_checked_ops", inherit_overflow_checks))]
+        { self.checked_div(rhs).unwrap_or_else(|| op_overflow("div", self, Self::from_i64(rhs))) }
+        #[cfg(not(all(feature = "debug_checked_ops", inherit_overflow_checks)))]
+        { self.unchecked_div(rhs) }
+    }
+}
+
+const_impl!{ impl UncheckedDiv<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_div(self, rhs: i64) -> Self {
+        Self::from_repr(self.repr / rhs as i128)
+    }
+}}
+
+const_impl!{ impl CheckedDiv<i64> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    #[allow(clippy::manual_map)]
+    fn checked_div(self, rhs: i64) -> Option<Self> {
+        if let Some(result) = self.repr.checked_div(rhs as i128) {
+            Some(Self::from_repr(result))
+        } else {
+            None
+        }
+    }
+}}
+
+// ========================
+// === Const Arithmetic ===
+// ========================
+
+/// Arithmetic usable in `const` contexts on the stable toolchain, where `const_impl!` does not make
+/// the trait implementations `const`. All methods panic on overflow, which in `const` contexts
+/// results in a compilation error.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// const A: Dec19x19 = Dec19x19!(1.5).add_const(Dec19x19!(2.25));
+/// const B: Dec19x19 = Dec19x19!(1.5).sub_const(Dec19x19!(2.25));
+/// const C: Dec19x19 = Dec19x19!(1.5).mul_const(Dec19x19!(-2.25));
+/// const D: Dec19x19 = Dec19x19!(1.5).neg_const();
+/// const E: Dec19x19 = Dec19x19::MIN.neg_const();
+/// const TABLE: [Dec19x19; 3] = {
+///     let step = Dec19x19!(0.1);
+///     let mut table = [Dec19x19!(1); 3];
+///     let mut i = 1;
+///     while i < 3 {
+///         table[i] = table[i - 1].mul_const(step);
+///         i += 1;
+///     }
+///     table
+/// };
+/// assert_eq!(A, Dec19x19!(3.75));
+/// assert_eq!(B, Dec19x19!(-0.75));
+/// assert_eq!(C, Dec19x19!(-3.375));
+/// assert_eq!(D, Dec19x19!(-1.5));
+/// assert_eq!(E, Dec19x19::MAX);
+/// assert_eq!(TABLE, [Dec19x19!(1), Dec19x19!(0.1), Dec19x19!(0.01)]);
+/// ```
+impl Dec19x19 {
+    /// Same as [`Add::add`], but usable in `const` contexts.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn add_const(self, rhs: Self) -> Self {
+        Self::from_repr(self.repr + rhs.repr)
+    }
+
+    /// Same as [`Sub::sub`], but usable in `const` contexts.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn sub_const(self, rhs: Self) -> Self {
+        Self::from_repr(self.repr - rhs.repr)
+    }
+
+    /// Same as [`Mul::mul`], but usable in `const` contexts.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn mul_const(self, rhs: Self) -> Self {
+        self.unchecked_mul_no_opt(rhs)
+    }
+
+    /// Same as [`Neg::neg`] without the `strict_neg` feature, but usable in `const` contexts.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn neg_const(self) -> Self {
+        if self.repr == i128::MIN {
+            Self::MAX
+        } else {
+            Self::from_repr(-self.repr)
+        }
+    }
+}
+
 // =============
 // === Trunc ===
 // =============
@@ -1115,6 +2698,33 @@ const_impl!{ impl TruncTo for Dec19x19 {
     }
 }}
 
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::split_int_frac] {
+///     (Dec19x19!(3.25))  => (Dec19x19!(3), Dec19x19!(0.25)),
+///     (Dec19x19!(-3.25)) => (Dec19x19!(-3), Dec19x19!(-0.25)),
+///     (Dec19x19!(3))     => (Dec19x19!(3), Dec19x19!(0)),
+///     (Dec19x19!(-0.25)) => (Dec19x19!(0), Dec19x19!(-0.25)),
+///     (Dec19x19!(0))     => (Dec19x19!(0), Dec19x19!(0)),
+///     (Dec19x19::MAX)    => (Dec19x19::MAX_INT, Dec19x19!(0.168_730_371_588_410_572_7)),
+///     (Dec19x19::MIN)    => (Dec19x19::MIN_INT, Dec19x19!(-0.168_730_371_588_410_572_8)),
+/// });
+/// ```
+impl Dec19x19 {
+    /// Splits the value into its integer part (the same as [`Trunc`]) and fractional part. Both
+    /// parts carry the sign of `self`, so their sum is always equal to `self`.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn split_int_frac(self) -> (Self, Self) {
+        let frac = self.repr % FRAC_SCALE_I128;
+        (Self { repr: self.repr - frac }, Self { repr: frac })
+    }
+}
+
 // =============
 // === Floor ===
 // =============
@@ -1368,12 +2978,115 @@ impl Dec19x19 {
     const fn round_impl(self, scale: i128, scale_half: i128) -> Self {
         let sign = self.repr >> 127; // 0 for +, -1 for -
         let bias = (scale_half ^ sign) - sign; // HALF or -HALF without branches
-        let rounded = if let Some(t) = self.repr.checked_add(bias) {
-            t / scale
-        } else {
-            self.repr / scale
-        };
-        Self { repr: rounded * scale }
+        self.round_with_bias(scale, bias)
+    }
+
+    /// Like [`Self::round_impl`], but with separate biases added to the magnitude of positive and
+    /// negative values, which allows choosing the direction of ties for each sign.
+    #[track_caller]
+    #[inline(always)]
+    const fn round_impl_biased(self, scale: i128, bias_pos: i128, bias_neg: i128) -> Self {
+        let bias = if self.repr < 0 { -bias_neg } else { bias_pos };
+        self.round_with_bias(scale, bias)
+    }
+
+    /// Adds `bias`, which has the sign of `self`, and truncates to a multiple of `scale`. If the
+    /// biased value overflows, the rounding is decided on the remainder instead, and the value is
+    /// rounded toward zero only if the multiple away from zero does not fit.
+    #[track_caller]
+    #[inline(always)]
+    const fn round_with_bias(self, scale: i128, bias: i128) -> Self {
+        if let Some(t) = self.repr.checked_add(bias) {
+            return Self { repr: t / scale * scale };
+        }
+        let truncated = self.repr / scale * scale;
+        let rem = self.repr - truncated;
+        if rem.unsigned_abs() + bias.unsigned_abs() < scale as u128 {
+            return Self { repr: truncated };
+        }
+        let step = if self.repr < 0 { -scale } else { scale };
+        match truncated.checked_add(step) {
+            Some(repr) => Self { repr },
+            None => Self { repr: truncated },
+        }
+    }
+
+    /// The bias that rounds ties toward zero, just below half of the scale.
+    #[inline(always)]
+    const fn tie_toward_zero_bias(scale: i128) -> i128 {
+        if scale > 1 { scale / 2 - 1 } else { 0 }
+    }
+
+    /// Rounds to the given number of fractional digits (negative values round the integer part),
+    /// to the nearest value, toward negative infinity on tie: `2.5` rounds to `2`, but `-2.5`
+    /// rounds to `-3`. Note that specifications (e.g. of payment systems) often mean rounding ties
+    /// toward zero by "half-down", which is [`Self::round_half_to_zero_to`]. See [`RoundingMode`]
+    /// for other modes.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::round_half_down_to] {
+    ///     (Dec19x19!(2.5), 0) => Dec19x19!(2),
+    ///     (Dec19x19!(-2.5), 0) => Dec19x19!(-3),
+    ///     (Dec19x19!(2.6), 0) => Dec19x19!(3),
+    ///     (Dec19x19!(-2.4), 0) => Dec19x19!(-2),
+    ///     (Dec19x19!(2.500_000_000_000_000_000_1), 0) => Dec19x19!(3),
+    ///     (Dec19x19!(-2.499_999_999_999_999_999_9), 0) => Dec19x19!(-2),
+    ///     (Dec19x19!(0.125), 2) => Dec19x19!(0.12),
+    ///     (Dec19x19!(-0.125), 2) => Dec19x19!(-0.13),
+    ///     (Dec19x19!(250), -2) => Dec19x19!(200),
+    ///     (Dec19x19!(-250), -2) => Dec19x19!(-300),
+    ///     (Dec19x19::SMALLEST_STEP, 19) => Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19::MAX, 0) => Dec19x19::MAX_INT,
+    ///     (Dec19x19::MIN, 0) => Dec19x19::MIN_INT,
+    ///     (Dec19x19::from_repr(i128::MAX - 28), 17) => Dec19x19::from_repr(i128::MAX - 27),
+    ///     (Dec19x19::from_repr(i128::MIN + 29), 17) => Dec19x19::from_repr(i128::MIN + 28),
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn round_half_down_to(self, digits: i64) -> Self {
+        let scale = crate::i128_ops::scale_for(digits);
+        self.round_impl_biased(scale, Self::tie_toward_zero_bias(scale), scale / 2)
+    }
+
+    /// Rounds to the given number of fractional digits (negative values round the integer part),
+    /// to the nearest value, toward zero on tie. See [`RoundingMode`] for other modes.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::round_half_to_zero_to] {
+    ///     (Dec19x19!(2.5), 0) => Dec19x19!(2),
+    ///     (Dec19x19!(-2.5), 0) => Dec19x19!(-2),
+    ///     (Dec19x19!(2.6), 0) => Dec19x19!(3),
+    ///     (Dec19x19!(-2.6), 0) => Dec19x19!(-3),
+    ///     (Dec19x19!(2.500_000_000_000_000_000_1), 0) => Dec19x19!(3),
+    ///     (Dec19x19!(-2.500_000_000_000_000_000_1), 0) => Dec19x19!(-3),
+    ///     (Dec19x19!(0.125), 2) => Dec19x19!(0.12),
+    ///     (Dec19x19!(-0.125), 2) => Dec19x19!(-0.12),
+    ///     (Dec19x19!(250), -2) => Dec19x19!(200),
+    ///     (Dec19x19!(-250), -2) => Dec19x19!(-200),
+    ///     (Dec19x19::SMALLEST_STEP, 19) => Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19::MAX, 0) => Dec19x19::MAX_INT,
+    ///     (Dec19x19::MIN, 0) => Dec19x19::MIN_INT,
+    ///     (Dec19x19::from_repr(i128::MAX - 28), 17) => Dec19x19::from_repr(i128::MAX - 27),
+    ///     (Dec19x19::from_repr(i128::MIN + 29), 17) => Dec19x19::from_repr(i128::MIN + 28),
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn round_half_to_zero_to(self, digits: i64) -> Self {
+        let scale = crate::i128_ops::scale_for(digits);
+        let bias = Self::tie_toward_zero_bias(scale);
+        self.round_impl_biased(scale, bias, bias)
     }
 }
 
@@ -1395,315 +3108,2604 @@ const_impl!{ impl RoundTo for Dec19x19 {
     }
 }}
 
-// ============
-// === Sqrt ===
-// ============
-
 /// # Tests
 ///
 /// ```
 /// # use fixed_num::*;
-/// # use validator::*;
-/// check! ( [Dec19x19::unchecked_sqrt, Dec19x19::checked_sqrt] {
-///     (Dec19x19!(0)) => Dec19x19!(0),
-///     (Dec19x19::MAX) => Dec19x19!(4_124_817_371.235_594_858_790_322_117_5),
-///     (-Dec19x19::SMALLEST_STEP) => FAIL,
-/// });
-/// // Precision test.
-/// assert_eq!(Dec19x19!(1e-18).unchecked_sqrt() * Dec19x19!(1e-18).unchecked_sqrt(), Dec19x19!(1e-18));
-/// ```
-///
-/// # Validation
-///
-/// ```
-/// # use fixed_num::*;
-/// # use validator::*;
-/// fuzzy1::<Dec19x19, BigDecimal>(Series::new(0..=19, 0..=19),
-///     |f1, b1| should_eq(f1.abs().unchecked_sqrt(), b1.abs().sqrt().unwrap())
-/// );
+/// let values = [
+///     Dec19x19!(3.14159), Dec19x19!(-3.14159), Dec19x19!(2.005), Dec19x19!(-2.005),
+///     Dec19x19::MAX, Dec19x19::MIN, Dec19x19::SMALLEST_STEP,
+/// ];
+/// for t in values {
+///     for digits in 0 ..= 19 {
+///         assert_eq!(t.rescale(digits), t.round_to(digits as i64));
+///     }
+///     assert_eq!(t.rescale(100), t);
+///     assert_eq!(t.normalize(), t);
+/// }
+/// assert_eq!(Dec19x19!(3.14159).rescale(2), Dec19x19!(3.14));
+/// assert_eq!(Dec19x19!(2.005).rescale(2), Dec19x19!(2.01));
+/// assert_eq!(Dec19x19!(1.500).normalize().to_string(), "1.5");
 /// ```
-impl UncheckedSqrt for Dec19x19 {
+impl Dec19x19 {
+    /// Rounds the value to `frac_digits` fractional digits, the same as [`RoundTo::round_to`].
+    /// Provided for source compatibility with `rust_decimal`. As the scale of [`Dec19x19`] is
+    /// fixed, the result still has 19 fractional digits, with the trailing ones set to zero.
+    /// Values above 19 are clamped to 19.
+    #[must_use]
     #[track_caller]
     #[inline(always)]
-    fn unchecked_sqrt(self) -> Self {
-        assert!(self.repr >= 0, "sqrt: negative number");
-        if self.repr == 0 {
-            return Self::from_repr(0);
-        }
-        let initial_guess = {
-            let self_f64 = self.repr as f64 / FRAC_SCALE_F64;
-            let approx_sqrt = self_f64.sqrt();
-            i256_from_i128((approx_sqrt * FRAC_SCALE_F64) as i128)
-        };
-        let x = i256_from_i128(self.repr);
-        let scale = FRAC_SCALE_I256;
-        let mut guess = initial_guess;
-        let mut last;
-
-        // Newton-Raphson loop
-        loop {
-            last = guess;
-            guess = (guess + (x * scale) / guess) / I256_TWO;
-            if (last - guess).wrapping_abs() <= i256::ONE {
-                break;
-            }
-        }
-        Self::from_repr(guess.as_i128())
+    pub const fn rescale(self, frac_digits: u32) -> Self {
+        let digits = if frac_digits > FRAC_PLACES { FRAC_PLACES } else { frac_digits };
+        let scale = crate::i128_ops::scale_for(digits as i64);
+        self.round_impl(scale, scale / 2)
     }
-}
 
-impl CheckedSqrt for Dec19x19 {
-    #[track_caller]
+    /// Returns `self` unchanged. Provided for source compatibility with `rust_decimal`. As the
+    /// scale of [`Dec19x19`] is fixed, there are no trailing zeros to strip from the
+    /// representation, and they are never displayed.
+    #[must_use]
     #[inline(always)]
-    fn checked_sqrt(self) -> Option<Self> {
-        if self.repr < 0 {
-            None
-        } else {
-            Some(self.unchecked_sqrt())
+    pub const fn normalize(self) -> Self {
+        self
+    }
+
+    /// Rounds the value like [`RoundTo::round_to`] and reports whether the result equals the
+    /// original value, i.e. whether the rounding was lossless.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::round_to_checked_exact] {
+    ///     (Dec19x19!(3.14), 2) => (Dec19x19!(3.14), true),
+    ///     (Dec19x19!(3.14), 5) => (Dec19x19!(3.14), true),
+    ///     (Dec19x19!(3.14159), 2) => (Dec19x19!(3.14), false),
+    ///     (Dec19x19!(-2.005), 2) => (Dec19x19!(-2.01), false),
+    ///     (Dec19x19!(1200), -2) => (Dec19x19!(1200), true),
+    ///     (Dec19x19!(1250), -2) => (Dec19x19!(1300), false),
+    ///     (Dec19x19::MAX, 19) => (Dec19x19::MAX, true),
+    ///     (Dec19x19!(1.123_456_789_012_345_678_5), 18) =>
+    ///         (Dec19x19!(1.123_456_789_012_345_679), false),
+    ///     (Dec19x19::SMALLEST_STEP, 0) => (Dec19x19!(0), false),
+    /// });
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[inline(always)]
+    pub const fn round_to_checked_exact(self, digits: i64) -> (Self, bool) {
+        let scale = crate::i128_ops::scale_for(digits);
+        let rounded = self.round_impl(scale, scale / 2);
+        (rounded, rounded.repr == self.repr)
+    }
+
+    /// The same as [`RoundTo::round_to`], which already saturates: if rounding away from zero
+    /// would overflow, the value is rounded toward zero instead, giving the closest representable
+    /// result with the requested number of digits. Provided to make this behavior explicit at the
+    /// call site. See [`Self::checked_round_to`] for the variant reporting such cases.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let values = [
+    ///     Dec19x19!(3.14159), Dec19x19!(-2.005), Dec19x19!(35), Dec19x19::SMALLEST_STEP,
+    ///     Dec19x19::MAX, Dec19x19::MIN, Dec19x19::MAX - Dec19x19!(1), Dec19x19::MIN_INT,
+    ///     Dec19x19::from_repr(i128::MAX - 28), Dec19x19::from_repr(i128::MIN + 29),
+    /// ];
+    /// for t in values {
+    ///     for digits in -20 ..= 20 {
+    ///         assert_eq!(t.saturating_round_to(digits), t.round_to(digits));
+    ///         if let Some(rounded) = t.checked_round_to(digits) {
+    ///             assert_eq!(t.saturating_round_to(digits), rounded);
+    ///         }
+    ///     }
+    /// }
+    /// // Adding the half of the scale overflows, but the rounded value `...700` fits.
+    /// let t = Dec19x19::from_repr(i128::MAX - 28);
+    /// assert_eq!(t.round_to(17), Dec19x19::from_repr(i128::MAX - 27));
+    /// assert_eq!(t.saturating_round_to(17), Dec19x19::from_repr(i128::MAX - 27));
+    /// assert_eq!(t.checked_round_to(17), Some(Dec19x19::from_repr(i128::MAX - 27)));
+    /// // Rounding up to `...173.2` would overflow.
+    /// assert_eq!(Dec19x19::MAX.saturating_round_to(1), Dec19x19!(17_014_118_346_046_923_173.1));
+    /// assert_eq!(Dec19x19::MIN.saturating_round_to(1), Dec19x19!(-17_014_118_346_046_923_173.1));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[inline(always)]
+    pub const fn saturating_round_to(self, digits: i64) -> Self {
+        let scale = crate::i128_ops::scale_for(digits);
+        self.round_impl(scale, scale / 2)
+    }
+
+    /// Rounds like [`RoundTo::round_to`], but returns `None` if the correctly rounded value does
+    /// not fit, instead of rounding toward zero.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::checked_round_to] {
+    ///     (Dec19x19!(3.14159), 2) => Dec19x19!(3.14),
+    ///     (Dec19x19!(-2.005), 2) => Dec19x19!(-2.01),
+    ///     (Dec19x19!(35), -1) => Dec19x19!(40),
+    ///     (Dec19x19::MAX, 0) => Dec19x19::MAX_INT,
+    ///     (Dec19x19::MAX, 19) => Dec19x19::MAX,
+    ///     (Dec19x19::MAX, 4) => Dec19x19!(17_014_118_346_046_923_173.168_7),
+    ///     (Dec19x19::MAX - Dec19x19!(1), 1) => Dec19x19!(17_014_118_346_046_923_172.2),
+    ///     (Dec19x19::MIN, 0) => Dec19x19::MIN_INT,
+    ///     (Dec19x19::MAX, 1) => FAIL,
+    ///     (Dec19x19::MAX, 2) => FAIL,
+    ///     (Dec19x19::MIN, 1) => FAIL,
+    ///     (Dec19x19::MAX, -19) => FAIL,
+    /// });
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[inline(always)]
+    pub const fn checked_round_to(self, digits: i64) -> Option<Self> {
+        let scale = crate::i128_ops::scale_for(digits);
+        let truncated = self.repr / scale * scale;
+        let rem = self.repr - truncated;
+        if rem.unsigned_abs() < (scale / 2) as u128 || scale == 1 {
+            return Some(Self { repr: truncated });
+        }
+        let step = if rem < 0 { -scale } else { scale };
+        match truncated.checked_add(step) {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
+    }
+
+    /// Rounds like [`RoundTo::round_to`], but returns `None` if `digits` is outside of
+    /// `-19..=19`, instead of silently clamping it. Use it to catch digit counts computed out of
+    /// range.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::strict_round_to] {
+    ///     (Dec19x19!(3.14159), 2) => Dec19x19!(3.14),
+    ///     (Dec19x19!(-2.005), 2) => Dec19x19!(-2.01),
+    ///     (Dec19x19!(35), -1) => Dec19x19!(40),
+    ///     (Dec19x19::SMALLEST_STEP, 19) => Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19::MAX, -19) => Dec19x19!(10_000_000_000_000_000_000),
+    ///     (Dec19x19!(3.14159), 20) => FAIL,
+    ///     (Dec19x19!(3.14159), -20) => FAIL,
+    /// });
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[inline(always)]
+    pub const fn strict_round_to(self, digits: i64) -> Option<Self> {
+        match crate::i128_ops::checked_scale_for(digits) {
+            Some(scale) => Some(self.round_impl(scale, scale / 2)),
+            None => None,
+        }
+    }
+
+    /// Truncates like [`TruncTo::trunc_to`], but returns `None` if `digits` is outside of
+    /// `-19..=19`, instead of silently clamping it.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::strict_trunc_to] {
+    ///     (Dec19x19!(3.14159), 2) => Dec19x19!(3.14),
+    ///     (Dec19x19!(-2.005), 2) => Dec19x19!(-2),
+    ///     (Dec19x19!(35), -1) => Dec19x19!(30),
+    ///     (Dec19x19::SMALLEST_STEP, 19) => Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19::MAX, -19) => Dec19x19!(10_000_000_000_000_000_000),
+    ///     (Dec19x19!(3.14159), 20) => FAIL,
+    ///     (Dec19x19!(3.14159), -20) => FAIL,
+    /// });
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[inline(always)]
+    pub const fn strict_trunc_to(self, digits: i64) -> Option<Self> {
+        match crate::i128_ops::checked_scale_for(digits) {
+            Some(scale) => Some(self.trunc_impl(scale)),
+            None => None,
         }
     }
 }
 
-// ==================
-// === Log10Floor ===
-// ==================
+// ========================
+// === Round to Integer ===
+// ========================
 
-const_impl!{
 /// # Tests
 ///
 /// ```
 /// # use fixed_num::*;
-/// # use validator::*;
-/// check! ( [Dec19x19::unchecked_log10_floor, Dec19x19::checked_log10_floor] {
-///     (Dec19x19::MAX)   => Dec19x19!(19),
-///     (Dec19x19!(10.1)) => Dec19x19!(1),
-///     (Dec19x19!(10.0)) => Dec19x19!(1),
-///     (Dec19x19!(9.99)) => Dec19x19!(0),
-///     (Dec19x19!(1.17)) => Dec19x19!(0),
-///     (Dec19x19!(1.0))  => Dec19x19!(0),
-///     (Dec19x19!(0.9))  => Dec19x19!(-1),
-///     (Dec19x19!(0.11)) => Dec19x19!(-1),
-///     (Dec19x19!(0.1))  => Dec19x19!(-1),
-///     (Dec19x19!(0.09)) => Dec19x19!(-2),
-///     (-Dec19x19::SMALLEST_STEP) => FAIL,
-/// });
+/// let values = [
+///     Dec19x19!(0), Dec19x19!(3.9), Dec19x19!(3.5), Dec19x19!(3.1), Dec19x19!(3.0),
+///     Dec19x19!(-3.9), Dec19x19!(-3.5), Dec19x19!(-3.1), Dec19x19!(-3.0),
+///     Dec19x19::SMALLEST_STEP, -Dec19x19::SMALLEST_STEP, Dec19x19::MAX_INT, Dec19x19::MIN_INT,
+///     Dec19x19::MAX - Dec19x19!(1), Dec19x19::MIN + Dec19x19!(1),
+/// ];
+/// for x in values {
+///     assert_eq!(x.trunc_to_i128(), i128::from(x.trunc()));
+///     assert_eq!(x.floor_to_i128(), i128::from(x.floor()));
+///     assert_eq!(x.ceil_to_i128(), i128::from(x.ceil()));
+///     assert_eq!(x.round_to_i128(), i128::from(x.round()));
+/// }
+///
+/// // Unlike `floor` and `ceil`, the integer variants are exact at the bounds.
+/// assert_eq!(Dec19x19::MAX.trunc_to_i128(), 17_014_118_346_046_923_173);
+/// assert_eq!(Dec19x19::MAX.floor_to_i128(), 17_014_118_346_046_923_173);
+/// assert_eq!(Dec19x19::MAX.ceil_to_i128(), 17_014_118_346_046_923_174);
+/// assert_eq!(Dec19x19::MAX.round_to_i128(), 17_014_118_346_046_923_173);
+/// assert_eq!(Dec19x19::MIN.trunc_to_i128(), -17_014_118_346_046_923_173);
+/// assert_eq!(Dec19x19::MIN.floor_to_i128(), -17_014_118_346_046_923_174);
+/// assert_eq!(Dec19x19::MIN.ceil_to_i128(), -17_014_118_346_046_923_173);
+/// assert_eq!(Dec19x19::MIN.round_to_i128(), -17_014_118_346_046_923_173);
 /// ```
-impl UncheckedLog10Floor for Dec19x19 {
+impl Dec19x19 {
+    /// Rounds toward zero and returns the integer directly. The integer part of any [`Dec19x19`]
+    /// fits in [`i128`], so this never overflows.
     #[track_caller]
     #[inline(always)]
-    fn unchecked_log10_floor(self) -> Self {
-        assert!(self.repr > 0);
-        // log10(repr / 10^19) = digit_count - 1 - 19
-        Self::from_i32(crate::i128_ops::digit_count(self.repr) - 20)
+    #[must_use]
+    pub const fn trunc_to_i128(self) -> i128 {
+        self.repr / FRAC_SCALE_I128
     }
-}}
 
-const_impl!{ impl CheckedLog10Floor for Dec19x19 {
+    /// Rounds toward negative infinity and returns the integer directly. Unlike [`Floor`], this
+    /// is exact also for values below [`Dec19x19::MIN_INT`] and never overflows.
     #[track_caller]
     #[inline(always)]
-    fn checked_log10_floor(self) -> Option<Self> {
-        if self.repr >= 0 {
-            Some(self.unchecked_log10_floor())
+    #[must_use]
+    pub const fn floor_to_i128(self) -> i128 {
+        self.repr.div_euclid(FRAC_SCALE_I128)
+    }
+
+    /// Rounds toward positive infinity and returns the integer directly. Unlike [`Ceil`], this is
+    /// exact also for values above [`Dec19x19::MAX_INT`] and never overflows.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn ceil_to_i128(self) -> i128 {
+        let int_part = self.repr / FRAC_SCALE_I128;
+        if self.repr % FRAC_SCALE_I128 > 0 { int_part + 1 } else { int_part }
+    }
+
+    /// Rounds to the nearest integer, away from zero on tie, and returns it directly. The result
+    /// always fits in [`i128`], so this never overflows.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn round_to_i128(self) -> i128 {
+        let int_part = self.repr / FRAC_SCALE_I128;
+        let frac_part = self.repr % FRAC_SCALE_I128;
+        if frac_part >= FRAC_SCALE_I128_HALF {
+            int_part + 1
+        } else if frac_part <= -FRAC_SCALE_I128_HALF {
+            int_part - 1
         } else {
-            None
+            int_part
         }
     }
-}}
+}
 
-// ==========
-// === Ln ===
-// ==========
+// ====================
+// === RoundingMode ===
+// ====================
 
-// sqrt(2) * 10^19   = 1.4142135623730950488e19
-const SQRT2_UP_I128: i128 = 14_142_135_623_730_950_488;
-// (10^19 / sqrt(2)) = 7.071067811865475244e18
-const SQRT2_DN_I128: i128 =  7_071_067_811_865_475_244;
+/// The direction in which a value is rounded when it is not exactly representable at the requested
+/// precision.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Toward zero, the same as [`Trunc`].
+    Trunc,
+    /// Toward negative infinity, the same as [`Floor`].
+    Floor,
+    /// Toward positive infinity, the same as [`Ceil`].
+    Ceil,
+    /// To the nearest value, away from zero on tie, the same as [`Round`].
+    Round,
+}
 
-const SQRT2_UP_I256: i256 = i256_from_i128(SQRT2_UP_I128);
-const SQRT2_DN_I256: i256 = i256_from_i128(SQRT2_DN_I128);
+// ================
+// === Quantize ===
+// ================
 
 /// # Tests
 ///
 /// ```
 /// # use fixed_num::*;
+/// # use fixed_num::dec19x19::RoundingMode::*;
 /// # use validator::*;
-/// let trunc = |t: Dec19x19| t.trunc_to(17);
-/// check!( [|t| trunc(Dec19x19::unchecked_ln(t)), |t| Dec19x19::checked_ln(t).map(trunc)] {
-///     (Dec19x19::MAX) =>  trunc(Dec19x19!(44.280_575_164_226_186_298_3)),
-///     (Dec19x19!(10)) =>  trunc(Dec19x19!(2.302_585_092_994_045_684_0)),
-///     (Dec19x19!(100)) => trunc(Dec19x19!(4.605_170_185_988_091_367_8)),
-///     (Dec19x19!(0.1)) => trunc(Dec19x19!(-2.302_585_092_994_045_683_7)),
-///     (Dec19x19!(2.718281828459045239)) => Dec19x19!(1),
-///     (-Dec19x19::SMALLEST_STEP) => FAIL,
+/// check! ( [Dec19x19::quantize_to_tick] {
+///     (Dec19x19!(100.37), Dec19x19!(0.05), Trunc) => Dec19x19!(100.35),
+///     (Dec19x19!(100.37), Dec19x19!(0.05), Floor) => Dec19x19!(100.35),
+///     (Dec19x19!(100.37), Dec19x19!(0.05), Ceil)  => Dec19x19!(100.40),
+///     (Dec19x19!(100.37), Dec19x19!(0.05), Round) => Dec19x19!(100.35),
+///     (Dec19x19!(100.38), Dec19x19!(0.05), Round) => Dec19x19!(100.40),
+///     (Dec19x19!(100.375), Dec19x19!(0.05), Round) => Dec19x19!(100.40),
+///
+///     (Dec19x19!(-100.37), Dec19x19!(0.05), Trunc) => Dec19x19!(-100.35),
+///     (Dec19x19!(-100.37), Dec19x19!(0.05), Floor) => Dec19x19!(-100.40),
+///     (Dec19x19!(-100.37), Dec19x19!(0.05), Ceil)  => Dec19x19!(-100.35),
+///     (Dec19x19!(-100.37), Dec19x19!(0.05), Round) => Dec19x19!(-100.35),
+///     (Dec19x19!(-100.375), Dec19x19!(0.05), Round) => Dec19x19!(-100.40),
+///
+///     // Exact multiples are not changed.
+///     (Dec19x19!(100.35), Dec19x19!(0.05), Ceil) => Dec19x19!(100.35),
+///     (Dec19x19!(-100.35), Dec19x19!(0.05), Floor) => Dec19x19!(-100.35),
+///
+///     // Ticks that are not powers of ten and negative ticks.
+///     (Dec19x19!(10), Dec19x19!(3), Round) => Dec19x19!(9),
+///     (Dec19x19!(10), Dec19x19!(-3), Ceil) => Dec19x19!(12),
+///
+///     // Zero tick.
+///     (Dec19x19!(100.37), Dec19x19!(0), Round) => Dec19x19!(100.37),
+///
+///     // Saturated toward zero if the rounded value does not fit, see
+///     // `Dec19x19::checked_round_to_step`.
+///     (Dec19x19::MAX, Dec19x19!(1), Ceil) => Dec19x19::MAX_INT,
+///     (Dec19x19::MIN, Dec19x19!(1), Floor) => Dec19x19::MIN_INT,
 /// });
 /// ```
-impl UncheckedLn for Dec19x19 {
+impl Dec19x19 {
+    /// Rounds `self` to a multiple of `tick` using the given rounding mode, the same as
+    /// [`Self::round_to_step`], under a name common in trading code.
     #[track_caller]
     #[inline(always)]
-    fn unchecked_ln(self) -> Self {
-        debug_assert!(self.repr > 0);
-
-        // 1) lift into i256
-        let mut v      = i256_from_i128(self.repr);
-        let scale      = FRAC_SCALE_I256;  // = 10^19 in i256
-        let two        = I256_TWO;
-        let ln2        = LN_2_I256;
-        let sqrt2_up   = SQRT2_UP_I256;    // = scale*√2
-        let sqrt2_dn   = SQRT2_DN_I256;    // = scale/√2
+    #[must_use]
+    pub fn quantize_to_tick(self, tick: Self, mode: RoundingMode) -> Self {
+        self.round_to_step(tick, mode)
+    }
 
-        // 2) range‑reduce v so that v ∈ [scale/√2, scale*√2]
-        let mut exp = 0i128;
-        while v > sqrt2_up {
-            v /= two;
-            exp += 1;
-        }
-        while v < sqrt2_dn {
-            v *= two;
-            exp -= 1;
+    /// Rounds `self` to a multiple of `step`, e.g. `0.25`, using the given rounding mode. The
+    /// sign of `step` is ignored. If `step` is zero, returns `self`. If the rounded value does not
+    /// fit, saturates by rounding toward zero instead, so near the bounds the result of
+    /// [`RoundingMode::Floor`] or [`RoundingMode::Ceil`] can be on the wrong side of `self`. Use
+    /// [`Self::checked_round_to_step`] to detect such cases.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::RoundingMode::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::round_to_step] {
+    ///     (Dec19x19!(1.3), Dec19x19!(0.25), Trunc)   => Dec19x19!(1.25),
+    ///     (Dec19x19!(1.3), Dec19x19!(0.25), Floor)   => Dec19x19!(1.25),
+    ///     (Dec19x19!(1.3), Dec19x19!(0.25), Ceil)    => Dec19x19!(1.5),
+    ///     (Dec19x19!(1.3), Dec19x19!(0.25), Round)   => Dec19x19!(1.25),
+    ///     (Dec19x19!(1.375), Dec19x19!(0.25), Round) => Dec19x19!(1.5),
+    ///     (Dec19x19!(-1.3), Dec19x19!(0.25), Trunc)  => Dec19x19!(-1.25),
+    ///     (Dec19x19!(-1.3), Dec19x19!(0.25), Floor)  => Dec19x19!(-1.5),
+    ///     (Dec19x19!(-1.3), Dec19x19!(0.25), Ceil)   => Dec19x19!(-1.25),
+    ///
+    ///     (Dec19x19!(6.2), Dec19x19!(2.5), Trunc)    => Dec19x19!(5),
+    ///     (Dec19x19!(6.2), Dec19x19!(2.5), Ceil)     => Dec19x19!(7.5),
+    ///     (Dec19x19!(6.2), Dec19x19!(2.5), Round)    => Dec19x19!(5),
+    ///     (Dec19x19!(6.25), Dec19x19!(2.5), Round)   => Dec19x19!(7.5),
+    ///     (Dec19x19!(-6.2), Dec19x19!(2.5), Floor)   => Dec19x19!(-7.5),
+    ///     (Dec19x19!(-6.2), Dec19x19!(-2.5), Round)  => Dec19x19!(-5),
+    ///     (Dec19x19!(7.5), Dec19x19!(2.5), Ceil)     => Dec19x19!(7.5),
+    ///
+    ///     (Dec19x19!(1.3), Dec19x19!(0), Ceil)       => Dec19x19!(1.3),
+    ///     (Dec19x19!(-1), Dec19x19::MIN, Floor)      => Dec19x19::MIN,
+    ///     (Dec19x19!(5), Dec19x19::MIN, Round)       => Dec19x19!(0),
+    ///
+    ///     // Saturated toward zero, as `...175` does not fit.
+    ///     (Dec19x19::MAX, Dec19x19!(2.5), Ceil)      => Dec19x19!(17_014_118_346_046_923_172.5),
+    ///     (Dec19x19::MAX, Dec19x19::MIN, Round)      => Dec19x19!(0),
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn round_to_step(self, step: Self, mode: RoundingMode) -> Self {
+        match self.round_to_step_impl(step, mode) {
+            Ok(rounded) | Err(rounded) => rounded,
         }
+    }
 
-        // 3) atanh trick: u = (v−scale)/(v+scale), scaled by `scale`
-        let num = v - scale;
-        let den = v + scale;
-        let u = (num * scale) / den;
+    /// Rounds `self` to a multiple of `step` like [`Self::round_to_step`], but returns `None` if
+    /// the rounded value does not fit, instead of rounding toward zero.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::RoundingMode::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::checked_round_to_step] {
+    ///     (Dec19x19!(1.3), Dec19x19!(0.25), Ceil)    => Dec19x19!(1.5),
+    ///     (Dec19x19!(-1.3), Dec19x19!(0.25), Floor)  => Dec19x19!(-1.5),
+    ///     (Dec19x19!(6.25), Dec19x19!(-2.5), Round)  => Dec19x19!(7.5),
+    ///     (Dec19x19!(1.3), Dec19x19!(0), Ceil)       => Dec19x19!(1.3),
+    ///     (Dec19x19::MAX, Dec19x19!(2.5), Floor)     => Dec19x19!(17_014_118_346_046_923_172.5),
+    ///     (Dec19x19::MAX, Dec19x19::SMALLEST_STEP, Ceil) => Dec19x19::MAX,
+    ///     (Dec19x19::MIN, Dec19x19!(1), Ceil)        => Dec19x19::MIN_INT,
+    ///     (Dec19x19!(-1), Dec19x19::MIN, Floor)      => Dec19x19::MIN,
+    ///     (Dec19x19::MIN, Dec19x19::MIN, Floor)      => Dec19x19::MIN,
+    ///     (Dec19x19::MAX, Dec19x19!(2.5), Ceil)      => FAIL,
+    ///     (Dec19x19::MAX, Dec19x19!(1), Ceil)        => FAIL,
+    ///     (Dec19x19::MAX, Dec19x19!(1), Round)       => Dec19x19::MAX_INT,
+    ///     (Dec19x19::MIN, Dec19x19!(1), Floor)       => FAIL,
+    ///     (Dec19x19!(1), Dec19x19::MIN, Ceil)        => FAIL,
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_round_to_step(self, step: Self, mode: RoundingMode) -> Option<Self> {
+        self.round_to_step_impl(step, mode).ok()
+    }
 
-        // 4) atanh-series: ln(v/scale) = 2·Σₖ [ u^(2k+1) / (2k+1) ]
-        let mut u_pow = u;
-        let mut sum   = u;
-        let mut k     = 1i128;
-        loop {
-            // u_pow ← u_pow · u² / scale²
-            u_pow = (u_pow * u / scale) * u / scale;
-            k += 2;
-            let term = u_pow / i256_from_i128(k);
-            if term == i256::ZERO {
-                break;
-            }
-            sum += term;
+    /// Rounds to a multiple of `step`, returning the value rounded toward zero as the error if the
+    /// correctly rounded one does not fit. The computation is done on magnitudes, so `step` can
+    /// be [`Self::MIN`].
+    fn round_to_step_impl(self, step: Self, mode: RoundingMode) -> Result<Self, Self> {
+        let tick = step.repr.unsigned_abs();
+        if tick == 0 {
+            return Ok(self);
         }
-        let ln_mant = sum * i256_from_i128(2);
-
-        // 5) add back exponent·ln(2)
-        let result = ln_mant + ln2 * i256_from_i128(exp);
+        let negative = self.repr < 0;
+        let mag = self.repr.unsigned_abs();
+        let rem = mag % tick;
+        let away_from_zero = rem != 0 && match mode {
+            RoundingMode::Trunc => false,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceil => !negative,
+            RoundingMode::Round => rem >= tick - rem,
+        };
+        let to_repr = |mag: u128| match negative {
+            true => 0_i128.checked_sub_unsigned(mag),
+            false => 0_i128.checked_add_unsigned(mag),
+        };
+        // Rounding toward zero does not increase the magnitude, so it always fits.
+        let truncated = Self::from_repr(to_repr(mag - rem).unwrap_or(self.repr));
+        if !away_from_zero {
+            return Ok(truncated);
+        }
+        match (mag - rem).checked_add(tick).and_then(to_repr) {
+            Some(repr) => Ok(Self::from_repr(repr)),
+            None => Err(truncated),
+        }
+    }
 
-        // 6) to Dec19x19, preserving your overflow‑checks cfg
-        #[cfg(inherit_overflow_checks)]
-        { Self::from_repr(i256_to_i128(result).expect("Overflow")) }
-        #[cfg(not(inherit_overflow_checks))]
-        { Self::from_repr(result.as_i128()) }
+    /// Rounds the value to `sig` significant digits using the given mode. Values that already
+    /// have at most `sig` significant digits are returned unchanged. A `sig` of zero is treated
+    /// as one. Like [`Self::round_to_step`], keeps the truncated value if rounding away from zero
+    /// would overflow.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::RoundingMode::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::round_to_significant] {
+    ///     (Dec19x19!(12345), 3, Round) => Dec19x19!(12300),
+    ///     (Dec19x19!(12355), 3, Round) => Dec19x19!(12400),
+    ///     (Dec19x19!(12345), 3, Ceil) => Dec19x19!(12400),
+    ///     (Dec19x19!(-12345), 3, Floor) => Dec19x19!(-12400),
+    ///     (Dec19x19!(-12345), 3, Trunc) => Dec19x19!(-12300),
+    ///     (Dec19x19!(0.001234), 3, Round) => Dec19x19!(0.00123),
+    ///     (Dec19x19!(0.001235), 3, Round) => Dec19x19!(0.00124),
+    ///     (Dec19x19!(-0.001234), 2, Round) => Dec19x19!(-0.0012),
+    ///     (Dec19x19!(9.99), 2, Round) => Dec19x19!(10),
+    ///     (Dec19x19!(1.5), 0, Round) => Dec19x19!(2),
+    ///     (Dec19x19!(1.5), 5, Round) => Dec19x19!(1.5),
+    ///     (Dec19x19!(0), 3, Round) => Dec19x19!(0),
+    ///     (Dec19x19::SMALLEST_STEP, 1, Round) => Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19!(0.000_000_000_000_000_123_4), 2, Round)
+    ///         => Dec19x19!(0.000_000_000_000_000_12),
+    ///     (Dec19x19::MAX, 3, Round) => Dec19x19!(17_000_000_000_000_000_000),
+    ///     (Dec19x19::MAX, 3, Ceil) => Dec19x19!(17_000_000_000_000_000_000),
+    ///     (Dec19x19::MIN, 2, Round) => Dec19x19!(-17_000_000_000_000_000_000),
+    ///     (Dec19x19::MAX, 39, Round) => Dec19x19::MAX,
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn round_to_significant(self, sig: u32, mode: RoundingMode) -> Self {
+        let digits = crate::i128_ops::digit_count(self.repr) as u32;
+        let sig = sig.max(1);
+        if self.repr == 0 || digits <= sig {
+            return self;
+        }
+        let step = Self::from_repr(crate::i128_ops::POW10[(digits - sig) as usize]);
+        self.round_to_step(step, mode)
     }
-}
 
-impl CheckedLn for Dec19x19 {
+    /// Truncates the value toward zero to `sig` significant digits, the same as
+    /// [`Self::round_to_significant`] with [`RoundingMode::Trunc`]. A `sig` of zero is treated as
+    /// one.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::trunc_to_significant] {
+    ///     (Dec19x19!(12399), 3) => Dec19x19!(12300),
+    ///     (Dec19x19!(-12399), 3) => Dec19x19!(-12300),
+    ///     (Dec19x19!(0.001_239_9), 3) => Dec19x19!(0.001_23),
+    ///     (Dec19x19!(-0.001_239_9), 1) => Dec19x19!(-0.001),
+    ///     (Dec19x19!(9.99), 0) => Dec19x19!(9),
+    ///     (Dec19x19!(1.5), 5) => Dec19x19!(1.5),
+    ///     (Dec19x19!(0), 3) => Dec19x19!(0),
+    ///     (Dec19x19::SMALLEST_STEP, 1) => Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19::MAX, 3) => Dec19x19!(17_000_000_000_000_000_000),
+    ///     (Dec19x19::MIN, 4) => Dec19x19!(-17_010_000_000_000_000_000),
+    ///     (Dec19x19::MAX, 39) => Dec19x19::MAX,
+    /// });
+    /// ```
     #[track_caller]
     #[inline(always)]
-    fn checked_ln(self) -> Option<Self> {
-        (self.repr > 0).then(|| self.unchecked_ln())
+    #[must_use]
+    pub fn trunc_to_significant(self, sig: u32) -> Self {
+        let digits = crate::i128_ops::digit_count(self.repr) as i64;
+        self.trunc_to(FRAC_PLACES as i64 + sig.max(1) as i64 - digits)
     }
 }
 
-// ===========
-// === Pow ===
-// ===========
+// ======================
+// === Scale by Pow10 ===
+// ======================
 
+const_impl!{
+/// # Tests
+///
 /// ```
 /// # use fixed_num::*;
 /// # use validator::*;
-/// check! ( [Dec19x19::unchecked_pow, Dec19x19::checked_pow] {
-///     // Identity and basic powers
-///     (Dec19x19!(2), 0_i32) => Dec19x19!(1),
-///     (Dec19x19!(2), 1_i32) => Dec19x19!(2),
-///     (Dec19x19!(2), 3_i32) => Dec19x19!(8),
-///     (Dec19x19!(2), 4_i32) => Dec19x19!(16),
-///     (Dec19x19!(2), 5_i32) => Dec19x19!(32),
-///     (Dec19x19!(2), 6_i32) => Dec19x19!(64),
-///     (Dec19x19!(2), 7_i32) => Dec19x19!(128),
-///     (Dec19x19!(2), 8_i32) => Dec19x19!(256),
-///     (Dec19x19!(2), 9_i32) => Dec19x19!(512),
-///     (Dec19x19!(2), 10_i32) => Dec19x19!(1024),
-///     (Dec19x19!(2), 11_i32) => Dec19x19!(2048),
-///     (Dec19x19!(2), 12_i32) => Dec19x19!(4096),
-///     (Dec19x19!(2), 13_i32) => Dec19x19!(8192),
-///     (Dec19x19!(2), 14_i32) => Dec19x19!(16384),
-///     (Dec19x19!(2), 15_i32) => Dec19x19!(32768),
-///     (Dec19x19!(2), 16_i32) => Dec19x19!(65536),
+/// check! ( [Dec19x19::checked_scale_by_pow10] {
+///     (Dec19x19!(1.5), 0)          => Dec19x19!(1.5),
+///     (Dec19x19!(1.5), 3)          => Dec19x19!(1500),
+///     (Dec19x19!(-1.5), 18)        => Dec19x19!(-1_500_000_000_000_000_000),
+///     (Dec19x19!(1500), -3)        => Dec19x19!(1.5),
+///     (Dec19x19!(-1.5), -18)       => Dec19x19!(-0.000_000_000_000_000_001_5),
+///     (Dec19x19::MAX_INT, -19)     => Dec19x19!(1.701_411_834_604_692_317_3),
+///     (Dec19x19!(0), 100)          => Dec19x19!(0),
+///     (Dec19x19!(0), -100)         => Dec19x19!(0),
 ///
-///     // Zero exponent
-///     (Dec19x19!(20), 0) => Dec19x19!(1),
+///     // Lossy down-shifts.
+///     (Dec19x19!(-1.5), -19)       => FAIL,
+///     (Dec19x19::SMALLEST_STEP, -1) => FAIL,
+///     (Dec19x19::MAX, -100)        => FAIL,
 ///
-///     // Negative exponents
-///     (Dec19x19!(2), -1_i32) => Dec19x19!(0.5),
-///     (Dec19x19!(2), -2_i32) => Dec19x19!(0.25),
+///     // Overflowing up-shifts.
+///     (Dec19x19!(2), 19)           => FAIL,
+///     (Dec19x19::SMALLEST_STEP, 39) => FAIL,
+///     (Dec19x19::MIN, 1)           => FAIL,
+/// });
 ///
-///     // Fractional bases
-///     (Dec19x19!(0.5), 2_i32) => Dec19x19!(0.25),
-///     (Dec19x19!(0.5), 3_i32) => Dec19x19!(0.125),
-///     (Dec19x19!(0.5), -1_i32) => Dec19x19!(2.0),
+/// check! ( [Dec19x19::scale_by_pow10, Dec19x19::checked_scale_by_pow10] {
+///     (Dec19x19!(1.5), 3)          => Dec19x19!(1500),
+///     (Dec19x19!(-1.5), -1)        => Dec19x19!(-0.15),
+///     (Dec19x19!(1), 19)           => Dec19x19!(1e19),
+///     (Dec19x19!(2), 19)           => FAIL,
+///     (Dec19x19::MIN, 1)           => FAIL,
+///     (Dec19x19::SMALLEST_STEP, 39) => FAIL,
+/// });
 ///
-///     // Fractional result rounding
-///     (Dec19x19!(1.1), 2_i32) => Dec19x19!(1.21),
-///     (Dec19x19!(1.5), 2_i32) => Dec19x19!(2.25),
+/// check! ( [Dec19x19::saturating_scale_by_pow10] {
+///     (Dec19x19!(1.5), 3)          => Dec19x19!(1500),
+///     (Dec19x19!(1), 19)           => Dec19x19!(1e19),
+///     (Dec19x19!(2), 19)           => Dec19x19::MAX,
+///     (Dec19x19!(-2), 19)          => Dec19x19::MIN,
+///     (Dec19x19::MIN, 1)           => Dec19x19::MIN,
+///     (Dec19x19::SMALLEST_STEP, 39) => Dec19x19::MAX,
+///     (Dec19x19!(0), 100)          => Dec19x19!(0),
 ///
-///     // Larger integer base
-///     (Dec19x19!(10), 3_i32) => Dec19x19!(1000),
+///     // Down-shifts truncate.
+///     (Dec19x19!(-1.5), -19)       => Dec19x19!(-0.000_000_000_000_000_000_1),
+///     (Dec19x19::SMALLEST_STEP, -1) => Dec19x19!(0),
+///     (Dec19x19::MAX, -100)        => Dec19x19!(0),
+/// });
 ///
-///     (Dec19x19::MAX, -1_i32) => Dec19x19!(0),
+/// // Lossy down-shifts truncate in the panicking variant too.
+/// assert_eq!(Dec19x19!(-1.55).scale_by_pow10(-1), Dec19x19!(-0.155));
+/// assert_eq!(Dec19x19::SMALLEST_STEP.scale_by_pow10(-1), Dec19x19!(0));
+/// assert_eq!(Dec19x19::MAX.scale_by_pow10(-100), Dec19x19!(0));
+/// ```
+impl ScaleByPow10 for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn scale_by_pow10(self, n: i32) -> Self {
+        if n >= 0 {
+            self.checked_scale_by_pow10(n).expect("Overflow in Dec19x19::scale_by_pow10")
+        } else {
+            self.saturating_scale_by_pow10(n)
+        }
+    }
+
+    #[inline(always)]
+    #[allow(clippy::manual_map)]
+    fn checked_scale_by_pow10(self, n: i32) -> Option<Self> {
+        use crate::i128_ops::POW10;
+        if self.repr == 0 {
+            return Some(self);
+        }
+        let idx = n.unsigned_abs() as usize;
+        if idx >= POW10.len() {
+            return None;
+        }
+        let scale = POW10[idx];
+        if n >= 0 {
+            match self.repr.checked_mul(scale) {
+                Some(repr) => Some(Self { repr }),
+                None => None,
+            }
+        } else if self.repr % scale == 0 {
+            Some(Self { repr: self.repr / scale })
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn saturating_scale_by_pow10(self, n: i32) -> Self {
+        use crate::i128_ops::POW10;
+        let idx = n.unsigned_abs() as usize;
+        if n >= 0 {
+            match self.checked_scale_by_pow10(n) {
+                Some(result) => result,
+                None => if self.repr < 0 { Self::MIN } else { Self::MAX },
+            }
+        } else if idx >= POW10.len() {
+            Dec19x19!(0)
+        } else {
+            Self { repr: self.repr / POW10[idx] }
+        }
+    }
+}}
+
+// ============
+// === Sqrt ===
+// ============
+
+/// # Tests
 ///
-///     (Dec19x19!(2), 63_i32) => Dec19x19!(9_223_372_036_854_775_808),
-///     (Dec19x19!(2), 64) => FAIL,
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_sqrt, Dec19x19::checked_sqrt] {
+///     (Dec19x19!(0)) => Dec19x19!(0),
+///     (Dec19x19::MAX) => Dec19x19!(4_124_817_371.235_594_858_790_322_117_5),
+///     (-Dec19x19::SMALLEST_STEP) => FAIL,
+/// });
+/// // Precision test.
+/// assert_eq!(Dec19x19!(1e-18).unchecked_sqrt() * Dec19x19!(1e-18).unchecked_sqrt(), Dec19x19!(1e-18));
+/// ```
 ///
-///     (Dec19x19!(0), -1_i32) => FAIL,
-///     (Dec19x19::MAX, 2_i32) => FAIL,
-///     (Dec19x19::MIN, 2_i32) => FAIL,
+/// # Validation
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// fuzzy1::<Dec19x19, BigDecimal>(Series::new(0..=19, 0..=19),
+///     |f1, b1| should_eq(f1.abs().unchecked_sqrt(), b1.abs().sqrt().unwrap())
+/// );
+/// ```
+impl UncheckedSqrt for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_sqrt(self) -> Self {
+        assert!(self.repr >= 0, "sqrt: negative number");
+        if self.repr == 0 {
+            return Self::from_repr(0);
+        }
+        let initial_guess = {
+            let self_f64 = self.repr as f64 / FRAC_SCALE_F64;
+            let approx_sqrt = self_f64.sqrt();
+            i256_from_i128((approx_sqrt * FRAC_SCALE_F64) as i128)
+        };
+        let x = i256_from_i128(self.repr);
+        let scale = FRAC_SCALE_I256;
+        let mut guess = initial_guess;
+        let mut last;
+
+        // Newton-Raphson loop
+        loop {
+            last = guess;
+            guess = (guess + (x * scale) / guess) / I256_TWO;
+            if (last - guess).wrapping_abs() <= i256::ONE {
+                break;
+            }
+        }
+        Self::from_repr(guess.as_i128())
+    }
+}
+
+impl CheckedSqrt for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn checked_sqrt(self) -> Option<Self> {
+        if self.repr < 0 {
+            None
+        } else {
+            Some(self.unchecked_sqrt())
+        }
+    }
+}
+
+impl Dec19x19 {
+    /// The integer square root, `floor(sqrt(self))`, or `None` if `self` is negative. As
+    /// `floor(sqrt(x)) == floor(sqrt(floor(x)))`, it is computed exactly from the integer part,
+    /// so, unlike `sqrt().floor()`, it is never off by one near perfect squares.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::isqrt] {
+    ///     (Dec19x19!(0)) => Dec19x19!(0),
+    ///     (Dec19x19!(0.99)) => Dec19x19!(0),
+    ///     (Dec19x19!(1)) => Dec19x19!(1),
+    ///     (Dec19x19!(8)) => Dec19x19!(2),
+    ///     (Dec19x19!(9)) => Dec19x19!(3),
+    ///     (Dec19x19!(9) - Dec19x19::SMALLEST_STEP) => Dec19x19!(2),
+    ///     (Dec19x19!(15.999)) => Dec19x19!(3),
+    ///     (Dec19x19::MAX) => Dec19x19!(4_124_817_371),
+    ///     (Dec19x19!(-1)) => FAIL,
+    ///     (-Dec19x19::SMALLEST_STEP) => FAIL,
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn isqrt(self) -> Option<Self> {
+        if self.repr < 0 {
+            return None;
+        }
+        let int = (self.repr / FRAC_SCALE_I128).isqrt();
+        Some(Self { repr: int * FRAC_SCALE_I128 })
+    }
+}
+
+// =============
+// === Hypot ===
+// =============
+
+/// The sum of squares is computed exactly in `i256`, so the result is the square root of the exact
+/// sum, truncated to 19 fractional digits.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_hypot, Dec19x19::checked_hypot] {
+///     (Dec19x19!(3), Dec19x19!(4))   => Dec19x19!(5),
+///     (Dec19x19!(-3), Dec19x19!(4))  => Dec19x19!(5),
+///     (Dec19x19!(0), Dec19x19!(0))   => Dec19x19!(0),
+///     (Dec19x19!(0), Dec19x19!(-7))  => Dec19x19!(7),
+///     (Dec19x19!(1), Dec19x19!(1))   => Dec19x19!(1.414_213_562_373_095_048_8),
+///     (Dec19x19::SMALLEST_STEP, Dec19x19::SMALLEST_STEP) => Dec19x19::SMALLEST_STEP,
+///     (Dec19x19::MAX, Dec19x19!(0))  => Dec19x19::MAX,
+///     (Dec19x19::MIN, Dec19x19!(0))  => FAIL,
+///
+///     // Squaring the operands would overflow `Dec19x19`.
+///     (Dec19x19!(10_000_000_000_000_000_000), Dec19x19!(10_000_000_000_000_000_000))
+///         => Dec19x19!(14_142_135_623_730_950_488.016_887_242_096_980_785_6),
+///     (Dec19x19::MAX, Dec19x19::MAX) => FAIL,
+///     (Dec19x19::MIN, Dec19x19::MIN) => FAIL,
 /// });
-///```
-impl UncheckedPow<i32> for Dec19x19 {
+/// ```
+impl UncheckedHypot for Dec19x19 {
     type Output = Self;
     #[track_caller]
     #[inline(always)]
-    fn unchecked_pow(self, exp: i32) -> Self::Output {
-        let mut result = Dec19x19!(1);
-        let mut base   = if exp >= 0 { self } else { Dec19x19!(1) / self };
-        let mut e      = exp.unsigned_abs();
-        macro_rules! step {() => {
-            let e2 = e / 2;
-            let f2 = e % 2;
-            if f2 == 1 {
-                result *= base;
-            }
-            e = e2;
-        };}
-        if e > 0 { step!(); }
-        while e > 0 {
-            base = base * base;
-            step!();
+    fn unchecked_hypot(self, other: Self) -> Self {
+        self.checked_hypot(other).expect("Overflow")
+    }
+}
+
+impl CheckedHypot for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn checked_hypot(self, other: Self) -> Option<Self> {
+        // Both values share the same scale, so sqrt(a² + b²) can be computed on the reprs directly.
+        let a = i256_from_i128(self.repr);
+        let b = i256_from_i128(other.repr);
+        // Overflows only if both values are `MIN`, in which case the result does not fit anyway.
+        let sum = (a * a).checked_add(b * b)?;
+        if sum == i256::ZERO {
+            return Some(Self::from_repr(0));
         }
-        result
+
+        let root = isqrt_i256_from_above(sum, a.wrapping_abs() + b.wrapping_abs());
+        i256_to_i128(root).map(Self::from_repr)
+    }
+}
+
+/// The floor of the square root of a positive `val`. The `guess` has to be positive and not
+/// smaller than the root.
+#[inline(always)]
+fn isqrt_i256_from_above(val: i256, mut guess: i256) -> i256 {
+    // Newton-Raphson loop, starting above the root, so that the guess decreases monotonically
+    // to the floor of the square root.
+    loop {
+        let next = (guess + val / guess) / I256_TWO;
+        if next >= guess {
+            break;
+        }
+        guess = next;
+    }
+    guess
+}
+
+// ==================
+// === Log10Floor ===
+// ==================
+
+const_impl!{
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_log10_floor, Dec19x19::checked_log10_floor] {
+///     (Dec19x19::MAX)   => Dec19x19!(19),
+///     (Dec19x19!(10.1)) => Dec19x19!(1),
+///     (Dec19x19!(10.0)) => Dec19x19!(1),
+///     (Dec19x19!(9.99)) => Dec19x19!(0),
+///     (Dec19x19!(1.17)) => Dec19x19!(0),
+///     (Dec19x19!(1.0))  => Dec19x19!(0),
+///     (Dec19x19!(0.9))  => Dec19x19!(-1),
+///     (Dec19x19!(0.11)) => Dec19x19!(-1),
+///     (Dec19x19!(0.1))  => Dec19x19!(-1),
+///     (Dec19x19!(0.09)) => Dec19x19!(-2),
+///     (-Dec19x19::SMALLEST_STEP) => FAIL,
+/// });
+/// ```
+impl UncheckedLog10Floor for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_log10_floor(self) -> Self {
+        assert!(self.repr > 0);
+        // log10(repr / 10^19) = digit_count - 1 - 19
+        Self::from_i32(crate::i128_ops::digit_count(self.repr) - 20)
+    }
+}}
+
+const_impl!{ impl CheckedLog10Floor for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    #[allow(clippy::if_then_some_else_none)]
+    fn checked_log10_floor(self) -> Option<Self> {
+        if self.repr >= 0 {
+            Some(self.unchecked_log10_floor())
+        } else {
+            None
+        }
+    }
+}}
+
+// ln(10) * 10^37 = 2.3025850929940456840179914546843642076e37
+const LN_10_E37_I128: i128 = 23_025_850_929_940_456_840_179_914_546_843_642_076;
+
+impl Dec19x19 {
+    /// The "symlog" transform, `signum(self) * log10(|self|)`, defined as `0` for zero. Useful for
+    /// plotting values of both signs on a symmetric logarithmic scale. The integer part of the
+    /// logarithm is exact, so powers of ten map to integers exactly. [`Dec19x19::MIN`] is treated
+    /// as [`Dec19x19::MAX`] in magnitude.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::signed_log10] {
+    ///     (Dec19x19!(100)) => Dec19x19!(2),
+    ///     (Dec19x19!(-100)) => Dec19x19!(-2),
+    ///     (Dec19x19!(0)) => Dec19x19!(0),
+    ///     (Dec19x19!(1)) => Dec19x19!(0),
+    ///     (Dec19x19!(-1)) => Dec19x19!(0),
+    ///     (Dec19x19!(1e18)) => Dec19x19!(18),
+    ///     (Dec19x19!(0.01)) => Dec19x19!(-2),
+    ///     (Dec19x19!(-0.01)) => Dec19x19!(2),
+    ///     (Dec19x19::SMALLEST_STEP) => Dec19x19!(-19),
+    /// });
+    ///
+    /// let trunc = |t: Dec19x19| t.trunc_to(17);
+    /// check! ( [|t| Dec19x19::signed_log10(t).trunc_to(17)] {
+    ///     (Dec19x19!(2)) => trunc(Dec19x19!(0.301_029_995_663_981_195_2)),
+    ///     (Dec19x19!(-2)) => trunc(Dec19x19!(-0.301_029_995_663_981_195_2)),
+    ///     (Dec19x19!(0.5)) => trunc(Dec19x19!(-0.301_029_995_663_981_195_2)),
+    ///     (Dec19x19!(12345.678)) => trunc(Dec19x19!(4.091_514_945_509_201_274_6)),
+    ///     (Dec19x19::MAX) => trunc(Dec19x19!(19.230_809_449_325_611_792_1)),
+    ///     (Dec19x19::MIN) => trunc(Dec19x19!(-19.230_809_449_325_611_792_1)),
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn signed_log10(self) -> Self {
+        use crate::i128_ops::POW10;
+        if self.repr == 0 {
+            return Dec19x19!(0);
+        }
+        let abs = self.abs();
+        // `|self| = mantissa * 10^exp`, where `mantissa` is in `1 .. 10`.
+        let exp = crate::i128_ops::digit_count(abs.repr) - 20;
+        let mantissa = if exp >= 0 {
+            abs.repr / POW10[exp as usize]
+        } else {
+            abs.repr * POW10[(-exp) as usize]
+        };
+        let ln_mantissa = i256_from_i128(Self::from_repr(mantissa).unchecked_ln().repr);
+        let frac = ln_mantissa * i256_from_i128(POW10[37]) / i256_from_i128(LN_10_E37_I128);
+        let log10 = Self::from_i32(exp).repr + frac.as_i128();
+        Self::from_repr(if self.repr < 0 { -log10 } else { log10 })
+    }
+}
+
+// ==========
+// === Ln ===
+// ==========
+
+// sqrt(2) * 10^19   = 1.4142135623730950488e19
+const SQRT2_UP_I128: i128 = 14_142_135_623_730_950_488;
+// (10^19 / sqrt(2)) = 7.071067811865475244e18
+const SQRT2_DN_I128: i128 =  7_071_067_811_865_475_244;
+
+const SQRT2_UP_I256: i256 = i256_from_i128(SQRT2_UP_I128);
+const SQRT2_DN_I256: i256 = i256_from_i128(SQRT2_DN_I128);
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// let trunc = |t: Dec19x19| t.trunc_to(17);
+/// check!( [|t| trunc(Dec19x19::unchecked_ln(t)), |t| Dec19x19::checked_ln(t).map(trunc)] {
+///     (Dec19x19::MAX) =>  trunc(Dec19x19!(44.280_575_164_226_186_298_3)),
+///     (Dec19x19!(10)) =>  trunc(Dec19x19!(2.302_585_092_994_045_684_0)),
+///     (Dec19x19!(100)) => trunc(Dec19x19!(4.605_170_185_988_091_367_8)),
+///     (Dec19x19!(0.1)) => trunc(Dec19x19!(-2.302_585_092_994_045_683_7)),
+///     (Dec19x19!(2.718281828459045239)) => Dec19x19!(1),
+///     (-Dec19x19::SMALLEST_STEP) => FAIL,
+/// });
+/// ```
+impl UncheckedLn for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_ln(self) -> Self {
+        debug_assert!(self.repr > 0);
+
+        // 1) lift into i256
+        let mut v      = i256_from_i128(self.repr);
+        let scale      = FRAC_SCALE_I256;  // = 10^19 in i256
+        let two        = I256_TWO;
+        let ln2        = LN_2_I256;
+        let sqrt2_up   = SQRT2_UP_I256;    // = scale*√2
+        let sqrt2_dn   = SQRT2_DN_I256;    // = scale/√2
+
+        // 2) range‑reduce v so that v ∈ [scale/√2, scale*√2]
+        let mut exp = 0i128;
+        while v > sqrt2_up {
+            v /= two;
+            exp += 1;
+        }
+        while v < sqrt2_dn {
+            v *= two;
+            exp -= 1;
+        }
+
+        // 3) atanh trick: u = (v−scale)/(v+scale), scaled by `scale`
+        let num = v - scale;
+        let den = v + scale;
+        let u = (num * scale) / den;
+
+        // 4) atanh-series: ln(v/scale) = 2·Σₖ [ u^(2k+1) / (2k+1) ]
+        let mut u_pow = u;
+        let mut sum   = u;
+        let mut k     = 1i128;
+        loop {
+            // u_pow ← u_pow · u² / scale²
+            u_pow = (u_pow * u / scale) * u / scale;
+            k += 2;
+            let term = u_pow / i256_from_i128(k);
+            if term == i256::ZERO {
+                break;
+            }
+            sum += term;
+        }
+        let ln_mant = sum * i256_from_i128(2);
+
+        // 5) add back exponent·ln(2)
+        let result = ln_mant + ln2 * i256_from_i128(exp);
+
+        // 6) to Dec19x19, preserving your overflow‑checks cfg
+        #[cfg(inherit_overflow_checks)]
+        { Self::from_repr(i256_to_i128(result).expect("Overflow")) }
+        #[cfg(not(inherit_overflow_checks))]
+        { Self::from_repr(result.as_i128()) }
+    }
+}
+
+impl CheckedLn for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn checked_ln(self) -> Option<Self> {
+        (self.repr > 0).then(|| self.unchecked_ln())
+    }
+}
+
+// ============
+// === Ln1p ===
+// ============
+
+/// Computes `ln(1 + x)` for `x` scaled by `10^38` and `|x| <= 1/2`, returning the result scaled by
+/// `10^38`.
+#[inline(always)]
+fn ln_1p_i256(x: i256) -> i256 {
+    let scale = EXP_SCALE_I256;
+
+    // atanh trick: u = x / (2 + x), ln(1 + x) = 2·Σₖ [ u^(2k+1) / (2k+1) ]
+    let u = x * scale / (I256_TWO * scale + x);
+    let u2 = u * u / scale;
+    let mut u_pow = u;
+    let mut sum = u;
+    let mut k = 1i128;
+    loop {
+        u_pow = u_pow * u2 / scale;
+        k += 2;
+        let term = u_pow / i256_from_i128(k);
+        if term == i256::ZERO {
+            break;
+        }
+        sum += term;
+    }
+    sum * I256_TWO
+}
+
+/// For `|self| <= 0.5`, the result is computed directly from the series expansion, so no precision
+/// is lost when computing `1 + self`. The result is truncated toward zero.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_ln_1p, Dec19x19::checked_ln_1p] {
+///     (Dec19x19!(0))              => Dec19x19!(0),
+///     (Dec19x19::SMALLEST_STEP)   => Dec19x19!(0),
+///     (-Dec19x19::SMALLEST_STEP)  => -Dec19x19::SMALLEST_STEP,
+///     (Dec19x19!(0.000_000_001))  => Dec19x19!(0.000_000_000_999_999_999_5),
+///     (Dec19x19!(-0.000_000_001)) => Dec19x19!(-0.000_000_001_000_000_000_5),
+///     (Dec19x19!(0.5))            => Dec19x19!(0.405_465_108_108_164_381_9),
+///     (Dec19x19!(-0.5))           => Dec19x19!(-0.693_147_180_559_945_309_4),
+///     (Dec19x19!(1))              => Dec19x19!(0.693_147_180_559_945_309_4),
+///     (Dec19x19!(-1))             => FAIL,
+///     (Dec19x19!(-2))             => FAIL,
+/// });
+/// assert_eq!(Dec19x19::MAX.unchecked_ln_1p(), Dec19x19::MAX.unchecked_ln());
+/// ```
+impl UncheckedLn1p for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_ln_1p(self) -> Self {
+        self.checked_ln_1p().expect("ln_1p: argument must be greater than -1")
+    }
+}
+
+impl CheckedLn1p for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn checked_ln_1p(self) -> Option<Self> {
+        if self <= Dec19x19!(-1) {
+            return None;
+        }
+        if self.abs() <= Dec19x19!(0.5) {
+            let x = i256_from_i128(self.repr) * FRAC_SCALE_I256;
+            return exp_scale_to_dec19x19(ln_1p_i256(x));
+        }
+        // If `1 + self` overflows, `ln(1 + self)` and `ln(self)` differ by less than the smallest
+        // representable step.
+        let one_plus_self = self.checked_add(Dec19x19!(1)).unwrap_or(self);
+        Some(one_plus_self.unchecked_ln())
+    }
+}
+
+// ===========
+// === Exp ===
+// ===========
+
+// Scale of the intermediate results of the exponential functions. The additional 19 digits keep
+// the accumulated error of the series below the precision of `Dec19x19`.
+const EXP_SCALE_I128: i128 = crate::i128_ops::POW10[38];
+const EXP_SCALE_I256: i256 = i256_from_i128(EXP_SCALE_I128);
+
+// ln(2) * 10^38, the remaining digits of ln(2) * 10^57 are stored in `LN_2_LO_I128`.
+const LN_2_HI_I128: i128 = 69_314_718_055_994_530_941_723_212_145_817_656_807;
+const LN_2_LO_I128: i128 = 5_500_134_360_255_254_120;
+
+// ln(10) * 10^37. It is multiplied by 10 at runtime, as ln(10) * 10^38 does not fit in `i128`.
+const LN_10_I128: i128 = 23_025_850_929_940_456_840_179_914_546_843_642_076;
+
+/// Computes `e^x` for `x` scaled by `10^38`, returning the result scaled by `10^38`. The input has
+/// to be in the range where the result fits in `Dec19x19`, otherwise the computation overflows.
+#[inline(always)]
+fn exp_i256(x: i256) -> i256 {
+    let scale = EXP_SCALE_I256;
+    let ln2_hi = i256_from_i128(LN_2_HI_I128);
+    let ln2_lo = i256_from_i128(LN_2_LO_I128);
+
+    // 1) range-reduce x = k·ln(2) + r, so that |r| <= ln(2) / 2
+    let ln2_half = ln2_hi / I256_TWO;
+    let k = if x >= i256::ZERO { (x + ln2_half) / ln2_hi } else { (x - ln2_half) / ln2_hi };
+    let r = x - k * ln2_hi - k * ln2_lo / FRAC_SCALE_I256;
+
+    // 2) e^r = 1 + (e^r - 1)
+    let sum = scale + exp_m1_series_i256(r);
+
+    // 3) multiply back by 2^k
+    let k = k.as_i128();
+    let pow2 = i256_from_i128(1 << k.unsigned_abs());
+    if k >= 0 { sum * pow2 } else { sum / pow2 }
+}
+
+/// Computes `e^x - 1` for `x` scaled by `10^38` using the Taylor series `Σₙ xⁿ / n!` (`n >= 1`),
+/// returning the result scaled by `10^38`. The series converges quickly for `|x| <= 1/2`.
+#[inline(always)]
+fn exp_m1_series_i256(x: i256) -> i256 {
+    let scale = EXP_SCALE_I256;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 2i128;
+    loop {
+        term = term * x / scale / i256_from_i128(n);
+        if term == i256::ZERO {
+            break;
+        }
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+/// Converts a value scaled by `10^38` to `Dec19x19`, truncating the digits that do not fit.
+#[inline(always)]
+fn exp_scale_to_dec19x19(val: i256) -> Option<Dec19x19> {
+    i256_to_i128(val / FRAC_SCALE_I256).map(Dec19x19::from_repr)
+}
+
+/// The result is truncated to 19 fractional digits. For results with a large integer part, the
+/// last digits might differ from the exact value, as the intermediate computations are performed
+/// with 38 significant digits.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_exp, Dec19x19::checked_exp] {
+///     (Dec19x19!(0))    => Dec19x19!(1),
+///     (Dec19x19!(1))    => Dec19x19!(2.718_281_828_459_045_235_3),
+///     (Dec19x19!(-1))   => Dec19x19!(0.367_879_441_171_442_321_5),
+///     (Dec19x19::LN_2)  => Dec19x19!(1.999_999_999_999_999_999_9),
+///     (Dec19x19!(30))   => Dec19x19!(10_686_474_581_524.462_146_990_468_650_741_4),
+///     (Dec19x19!(-43))  => Dec19x19!(0.000_000_000_000_000_000_2),
+///     (Dec19x19!(-50))  => Dec19x19!(0),
+///     (Dec19x19::MIN)   => Dec19x19!(0),
+///     (Dec19x19!(44.3)) => FAIL,
+///     (Dec19x19::MAX)   => FAIL,
+/// });
+/// ```
+impl UncheckedExp for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_exp(self) -> Self {
+        self.checked_exp().expect("Overflow")
+    }
+}
+
+impl CheckedExp for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn checked_exp(self) -> Option<Self> {
+        // e^45 overflows and e^-45 rounds to zero.
+        if self > Dec19x19!(45) {
+            return None;
+        }
+        if self < Dec19x19!(-45) {
+            return Some(Dec19x19!(0));
+        }
+        let x = i256_from_i128(self.repr) * FRAC_SCALE_I256;
+        exp_scale_to_dec19x19(exp_i256(x))
+    }
+}
+
+// =============
+// === Exp10 ===
+// =============
+
+/// Integer exponents are computed exactly using the powers of ten table. Other exponents have the
+/// same precision as [`Dec19x19::checked_exp`].
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_exp10, Dec19x19::checked_exp10] {
+///     (Dec19x19!(0))    => Dec19x19!(1),
+///     (Dec19x19!(3))    => Dec19x19!(1000),
+///     (Dec19x19!(-2))   => Dec19x19!(0.01),
+///     (Dec19x19!(19))   => Dec19x19!(10_000_000_000_000_000_000),
+///     (Dec19x19!(-19))  => Dec19x19::SMALLEST_STEP,
+///     (Dec19x19!(-20))  => Dec19x19!(0),
+///     (Dec19x19!(0.5))  => Dec19x19!(3.162_277_660_168_379_331_9),
+///     (Dec19x19!(-0.5)) => Dec19x19!(0.316_227_766_016_837_933_1),
+///     (Dec19x19!(2.5))  => Dec19x19!(316.227_766_016_837_933_199_8),
+///     (Dec19x19::MIN)   => Dec19x19!(0),
+///     (Dec19x19!(20))   => FAIL,
+///     (Dec19x19!(19.5)) => FAIL,
+///     (Dec19x19::MAX)   => FAIL,
+/// });
+/// ```
+impl UncheckedExp10 for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_exp10(self) -> Self {
+        self.checked_exp10().expect("Overflow")
+    }
+}
+
+impl CheckedExp10 for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn checked_exp10(self) -> Option<Self> {
+        if self >= Dec19x19!(20) {
+            return None;
+        }
+        if self <= Dec19x19!(-20) {
+            return Some(Dec19x19!(0));
+        }
+        let int = self.floor();
+        let frac = self - int;
+        let pow10 = |n: i128| crate::i128_ops::POW10[n as usize];
+        let int_exp = int.repr / FRAC_SCALE_I128;
+        if frac.is_zero() {
+            // Fast path for integer exponents.
+            let repr = if int_exp >= -19 { pow10(int_exp + 19) } else { 0 };
+            return Some(Self::from_repr(repr));
+        }
+        // 10^x = 10^int · e^(frac·ln(10)), where 0 < frac < 1.
+        let ln10 = i256_from_i128(LN_10_I128) * i256_from_i128(10);
+        let x = i256_from_i128(frac.repr) * ln10 / FRAC_SCALE_I256;
+        let exp_frac = exp_i256(x);
+        let val = if int_exp >= 0 {
+            exp_frac * i256_from_i128(pow10(int_exp))
+        } else {
+            exp_frac / i256_from_i128(pow10(-int_exp))
+        };
+        exp_scale_to_dec19x19(val)
+    }
+}
+
+// ============
+// === Exp2 ===
+// ============
+
+/// Integer exponents are computed exactly using bit shifts. Other exponents have the same
+/// precision as [`Dec19x19::checked_exp`].
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_exp2, Dec19x19::checked_exp2] {
+///     (Dec19x19!(0))    => Dec19x19!(1),
+///     (Dec19x19!(10))   => Dec19x19!(1024),
+///     (Dec19x19!(-1))   => Dec19x19!(0.5),
+///     (Dec19x19!(-10))  => Dec19x19!(0.000_976_562_5),
+///     (Dec19x19!(63))   => Dec19x19!(9_223_372_036_854_775_808),
+///     (Dec19x19!(-63))  => Dec19x19::SMALLEST_STEP,
+///     (Dec19x19!(-64))  => Dec19x19!(0),
+///     (Dec19x19!(0.5))  => Dec19x19!(1.414_213_562_373_095_048_8),
+///     (Dec19x19!(-0.5)) => Dec19x19!(0.707_106_781_186_547_524_4),
+///     (Dec19x19!(10.5)) => Dec19x19!(1_448.154_687_870_049_329_972_9),
+///     (Dec19x19!(63.9)) => FAIL,
+///     (Dec19x19::MIN)   => Dec19x19!(0),
+///     (Dec19x19!(64))   => FAIL,
+///     (Dec19x19::MAX)   => FAIL,
+/// });
+/// ```
+impl UncheckedExp2 for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_exp2(self) -> Self {
+        self.checked_exp2().expect("Overflow")
+    }
+}
+
+impl CheckedExp2 for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn checked_exp2(self) -> Option<Self> {
+        if self >= Dec19x19!(64) {
+            return None;
+        }
+        if self <= Dec19x19!(-66) {
+            return Some(Dec19x19!(0));
+        }
+        let int = self.floor();
+        let frac = self - int;
+        let int_exp = int.repr / FRAC_SCALE_I128;
+        if frac.is_zero() {
+            // Fast path for integer exponents.
+            let repr = if int_exp >= 0 {
+                FRAC_SCALE_I128.checked_mul(1 << int_exp)?
+            } else {
+                FRAC_SCALE_I128 >> -int_exp
+            };
+            return Some(Self::from_repr(repr));
+        }
+        // 2^x = 2^int · e^(frac·ln(2)), where 0 < frac < 1.
+        let ln2 = i256_from_i128(LN_2_HI_I128);
+        let x = i256_from_i128(frac.repr) * ln2 / FRAC_SCALE_I256;
+        let exp_frac = exp_i256(x);
+        let pow2 = i256_from_i128(1 << int_exp.unsigned_abs());
+        let val = if int_exp >= 0 { exp_frac * pow2 } else { exp_frac / pow2 };
+        exp_scale_to_dec19x19(val)
+    }
+}
+
+// =============
+// === ExpM1 ===
+// =============
+
+/// For `|self| <= 0.5`, the result is computed directly from the series expansion, so no precision
+/// is lost to the cancellation of `e^self - 1`. The result is truncated toward zero.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_exp_m1, Dec19x19::checked_exp_m1] {
+///     (Dec19x19!(0))                       => Dec19x19!(0),
+///     (Dec19x19::SMALLEST_STEP)            => Dec19x19::SMALLEST_STEP,
+///     (-Dec19x19::SMALLEST_STEP)           => -Dec19x19::SMALLEST_STEP,
+///     (Dec19x19!(0.000_000_001))           => Dec19x19!(0.000_000_001_000_000_000_5),
+///     (Dec19x19!(-0.000_000_001))          => Dec19x19!(-0.000_000_000_999_999_999_5),
+///     (Dec19x19!(1))                       => Dec19x19!(1.718_281_828_459_045_235_3),
+///     (Dec19x19!(-50))                     => Dec19x19!(-1),
+///     (Dec19x19::MIN)                      => Dec19x19!(-1),
+///     (Dec19x19::MAX)                      => FAIL,
+/// });
+/// ```
+///
+/// # Validation
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// fuzzy1::<Dec19x19, BigDecimal>(Series::new(0, 0..=19),
+///     |f1, b1| should_eq(f1.unchecked_exp_m1(), b1.exp() - BigDecimal::from(1))
+/// );
+/// ```
+impl UncheckedExpM1 for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_exp_m1(self) -> Self {
+        self.checked_exp_m1().expect("Overflow")
+    }
+}
+
+impl CheckedExpM1 for Dec19x19 {
+    #[track_caller]
+    #[inline(always)]
+    fn checked_exp_m1(self) -> Option<Self> {
+        if self > Dec19x19!(45) {
+            return None;
+        }
+        if self < Dec19x19!(-45) {
+            return Some(Dec19x19!(-1));
+        }
+        let x = i256_from_i128(self.repr) * FRAC_SCALE_I256;
+        if self.abs() <= Dec19x19!(0.5) {
+            exp_scale_to_dec19x19(exp_m1_series_i256(x))
+        } else {
+            exp_scale_to_dec19x19(exp_i256(x) - EXP_SCALE_I256)
+        }
+    }
+}
+
+// ===========
+// === Pow ===
+// ===========
+
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::unchecked_pow, Dec19x19::checked_pow] {
+///     // Identity and basic powers
+///     (Dec19x19!(2), 0_i32) => Dec19x19!(1),
+///     (Dec19x19!(2), 1_i32) => Dec19x19!(2),
+///     (Dec19x19!(2), 3_i32) => Dec19x19!(8),
+///     (Dec19x19!(2), 4_i32) => Dec19x19!(16),
+///     (Dec19x19!(2), 5_i32) => Dec19x19!(32),
+///     (Dec19x19!(2), 6_i32) => Dec19x19!(64),
+///     (Dec19x19!(2), 7_i32) => Dec19x19!(128),
+///     (Dec19x19!(2), 8_i32) => Dec19x19!(256),
+///     (Dec19x19!(2), 9_i32) => Dec19x19!(512),
+///     (Dec19x19!(2), 10_i32) => Dec19x19!(1024),
+///     (Dec19x19!(2), 11_i32) => Dec19x19!(2048),
+///     (Dec19x19!(2), 12_i32) => Dec19x19!(4096),
+///     (Dec19x19!(2), 13_i32) => Dec19x19!(8192),
+///     (Dec19x19!(2), 14_i32) => Dec19x19!(16384),
+///     (Dec19x19!(2), 15_i32) => Dec19x19!(32768),
+///     (Dec19x19!(2), 16_i32) => Dec19x19!(65536),
+///
+///     // Zero exponent
+///     (Dec19x19!(20), 0) => Dec19x19!(1),
+///
+///     // Negative exponents
+///     (Dec19x19!(2), -1_i32) => Dec19x19!(0.5),
+///     (Dec19x19!(2), -2_i32) => Dec19x19!(0.25),
+///
+///     // Fractional bases
+///     (Dec19x19!(0.5), 2_i32) => Dec19x19!(0.25),
+///     (Dec19x19!(0.5), 3_i32) => Dec19x19!(0.125),
+///     (Dec19x19!(0.5), -1_i32) => Dec19x19!(2.0),
+///
+///     // Fractional result rounding
+///     (Dec19x19!(1.1), 2_i32) => Dec19x19!(1.21),
+///     (Dec19x19!(1.5), 2_i32) => Dec19x19!(2.25),
+///
+///     // Larger integer base
+///     (Dec19x19!(10), 3_i32) => Dec19x19!(1000),
+///
+///     (Dec19x19::MAX, -1_i32) => Dec19x19!(0),
+///
+///     (Dec19x19!(2), 63_i32) => Dec19x19!(9_223_372_036_854_775_808),
+///     (Dec19x19!(2), 64) => FAIL,
+///
+///     // Negative bases alternate the sign of the result.
+///     (Dec19x19!(-2), 63_i32) => Dec19x19!(-9_223_372_036_854_775_808),
+///     (Dec19x19!(-2), 64_i32) => FAIL,
+///     (Dec19x19!(-2), -3_i32) => Dec19x19!(-0.125),
+///     (Dec19x19!(-1.5), 3_i32) => Dec19x19!(-3.375),
+///     (Dec19x19!(-1.5), 4_i32) => Dec19x19!(5.0625),
+///     (Dec19x19!(-1), 1_000_001_i32) => Dec19x19!(-1),
+///     (Dec19x19!(-1), 1_000_000_i32) => Dec19x19!(1),
+///
+///     (Dec19x19!(0), -1_i32) => FAIL,
+///     (Dec19x19::SMALLEST_STEP, -2_i32) => FAIL,
+///     (Dec19x19::MAX, 2_i32) => FAIL,
+///     (Dec19x19::MIN, 2_i32) => FAIL,
+///     (Dec19x19::MIN, 3_i32) => FAIL,
+/// });
+///```
+impl UncheckedPow<i32> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn unchecked_pow(self, exp: i32) -> Self::Output {
+        let mut result = Dec19x19!(1);
+        let mut base   = if exp >= 0 { self } else { Dec19x19!(1) / self };
+        let mut e      = exp.unsigned_abs();
+        macro_rules! step {() => {
+            let e2 = e / 2;
+            let f2 = e % 2;
+            if f2 == 1 {
+                result *= base;
+            }
+            e = e2;
+        };}
+        if e > 0 { step!(); }
+        while e > 0 {
+            base = base * base;
+            step!();
+        }
+        result
+    }
+}
+
+impl CheckedPow<i32> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn checked_pow(self, exp: i32) -> Option<Self::Output> {
+        let mut result = Dec19x19!(1);
+        let mut base   = if exp >= 0 { self } else { Dec19x19!(1).checked_div(self)? };
+        let mut e      = exp.unsigned_abs();
+        macro_rules! step {() => {
+            let e2 = e / 2;
+            let f2 = e % 2;
+            if f2 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            e = e2;
+        };}
+        if e > 0 { step!(); }
+        while e > 0 {
+            base = base.checked_mul(base)?;
+            step!();
+        }
+        Some(result)
+    }
+}
+
+impl Dec19x19 {
+    /// Raises the value to a `u64` power by repeated squaring. Returns `None` as soon as an
+    /// intermediate product overflows and stops early once the base underflows to zero, so at
+    /// most 64 squarings are performed, no matter the exponent.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::checked_pow_u64] {
+    ///     (Dec19x19!(2), 0_u64) => Dec19x19!(1),
+    ///     (Dec19x19!(0), 0_u64) => Dec19x19!(1),
+    ///     (Dec19x19!(2), 1_u64) => Dec19x19!(2),
+    ///     (Dec19x19!(2), 10_u64) => Dec19x19!(1024),
+    ///     (Dec19x19!(1.1), 2_u64) => Dec19x19!(1.21),
+    ///     (Dec19x19!(-1.5), 3_u64) => Dec19x19!(-3.375),
+    ///     (Dec19x19!(2), 63_u64) => Dec19x19!(9_223_372_036_854_775_808),
+    ///     (Dec19x19!(2), 64_u64) => FAIL,
+    ///     (Dec19x19!(-2), 63_u64) => Dec19x19!(-9_223_372_036_854_775_808),
+    ///     (Dec19x19!(-2), 64_u64) => FAIL,
+    ///     (Dec19x19::MAX, 2_u64) => FAIL,
+    ///
+    ///     // Huge exponents finish quickly.
+    ///     (Dec19x19!(2), u64::MAX) => FAIL,
+    ///     (Dec19x19!(1e10), u64::MAX) => FAIL,
+    ///     (Dec19x19!(1), u64::MAX) => Dec19x19!(1),
+    ///     (Dec19x19!(-1), u64::MAX) => Dec19x19!(-1),
+    ///     (Dec19x19!(-1), u64::MAX - 1) => Dec19x19!(1),
+    ///     (Dec19x19!(0.5), u64::MAX) => Dec19x19!(0),
+    ///     (Dec19x19!(0), u64::MAX) => Dec19x19!(0),
+    /// });
+    ///
+    /// // Agrees with `checked_pow` on the `i32` range.
+    /// for exp in 0..=70 {
+    ///     for base in [Dec19x19!(1.7), Dec19x19!(-3), Dec19x19!(0.9)] {
+    ///         assert_eq!(base.checked_pow_u64(exp), base.checked_pow(exp as i32));
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_pow_u64(self, exp: u64) -> Option<Self> {
+        let mut result = Dec19x19!(1);
+        let mut base   = self;
+        let mut e      = exp;
+        loop {
+            if e % 2 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            e /= 2;
+            if e == 0 || result.is_zero() {
+                return Some(result);
+            }
+            base = base.checked_mul(base)?;
+            if base.is_zero() {
+                return Some(Dec19x19!(0));
+            }
+        }
+    }
+
+    /// Iterates over successive integer powers of `base`: `1, base, base², base³, …`. Each item
+    /// is the previous one multiplied by `base`, and the iteration stops before the first power
+    /// that overflows.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let powers: Vec<_> = Dec19x19::powers_of(Dec19x19!(2)).take(5).collect();
+    /// let expected = [Dec19x19!(1), Dec19x19!(2), Dec19x19!(4), Dec19x19!(8), Dec19x19!(16)];
+    /// assert_eq!(powers, expected);
+    ///
+    /// for base in [Dec19x19!(2), Dec19x19!(-1.5), Dec19x19!(1.1), Dec19x19!(0.5)] {
+    ///     for (exp, t) in Dec19x19::powers_of(base).take(19).enumerate() {
+    ///         assert_eq!(Some(t), base.checked_pow(exp as i32), "{base}^{exp}");
+    ///     }
+    /// }
+    ///
+    /// // Stops on overflow.
+    /// assert_eq!(Dec19x19::powers_of(Dec19x19!(2)).count(), 64);
+    /// let last = Dec19x19::powers_of(Dec19x19!(-2)).last();
+    /// assert_eq!(last, Some(Dec19x19!(-9_223_372_036_854_775_808)));
+    /// assert_eq!(Dec19x19::powers_of(Dec19x19::MAX).count(), 2);
+    /// ```
+    #[inline(always)]
+    pub fn powers_of(base: Self) -> impl Iterator<Item = Self> {
+        std::iter::successors(Some(Dec19x19!(1)), move |t| t.checked_mul(base))
+    }
+
+    /// Compound growth, `self * (1 + rate)^periods`, or `None` on overflow. Negative `periods`
+    /// discount the value instead.
+    ///
+    /// The growth factor is computed by repeated squaring, and every multiplication truncates
+    /// the result to 19 fractional digits. The rounding error thus accumulates with the number of
+    /// periods and is scaled by `self`. For example, `1.001^1000` is about 20 units in the last
+    /// place below the exact value. Round the result to the precision you need, e.g. cents.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::compound] {
+    ///     (Dec19x19!(100), Dec19x19!(0.1), 3) => Dec19x19!(133.1),
+    ///     (Dec19x19!(2500), Dec19x19!(-0.2), 2) => Dec19x19!(1600),
+    ///     (Dec19x19!(1000), Dec19x19!(0), 50) => Dec19x19!(1000),
+    ///     (Dec19x19!(1000), Dec19x19!(0.05), 0) => Dec19x19!(1000),
+    ///     // Exact: 1628.894_626_777_441_406_25
+    ///     (Dec19x19!(1000), Dec19x19!(0.05), 10) => Dec19x19!(1628.894_626_777_441_406_2),
+    ///     // Exact: 1126.825_030_131_969_720_661_201
+    ///     (Dec19x19!(1000), Dec19x19!(0.01), 12) => Dec19x19!(1126.825_030_131_969_720_6),
+    ///     // Exact: 907.029_478_458_049_886_621_3…
+    ///     (Dec19x19!(1000), Dec19x19!(0.05), -2) => Dec19x19!(907.029_478_458_049_886_5),
+    ///     // Exact: 2.716_923_932_235_892_457_38…
+    ///     (Dec19x19!(1), Dec19x19!(0.001), 1000) => Dec19x19!(2.716_923_932_235_892_437_5),
+    ///     (Dec19x19!(1000), Dec19x19!(1), 64) => FAIL,
+    ///     (Dec19x19!(1000), Dec19x19!(-1), -1) => FAIL,
+    ///     (Dec19x19!(1), Dec19x19::MAX, 1) => FAIL,
+    /// });
+    /// assert_eq!(
+    ///     Dec19x19!(1000).compound(Dec19x19!(0.05), 10).map(|t| t.round_to(2)),
+    ///     Some(Dec19x19!(1628.89))
+    /// );
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn compound(self, rate: Self, periods: i32) -> Option<Self> {
+        let factor = Dec19x19!(1).checked_add(rate)?.checked_pow(periods)?;
+        self.checked_mul(factor)
+    }
+}
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::saturating_pow] {
+///     (Dec19x19!(2), 63_i32) => Dec19x19!(9_223_372_036_854_775_808),
+///     (Dec19x19!(2), 64_i32) => Dec19x19::MAX,
+///     (Dec19x19!(-2), 63_i32) => Dec19x19!(-9_223_372_036_854_775_808),
+///     (Dec19x19!(-2), 64_i32) => Dec19x19::MAX,
+///     (Dec19x19!(-2), 65_i32) => Dec19x19::MIN,
+///     (Dec19x19!(-1.5), 3_i32) => Dec19x19!(-3.375),
+///     (Dec19x19::MIN, 2_i32) => Dec19x19::MAX,
+///     (Dec19x19::MIN, 3_i32) => Dec19x19::MIN,
+///     (Dec19x19!(0), -1_i32) => Dec19x19::MAX,
+///     (Dec19x19::SMALLEST_STEP, -2_i32) => Dec19x19::MAX,
+///     (-Dec19x19::SMALLEST_STEP, -2_i32) => Dec19x19::MAX,
+///     (-Dec19x19::SMALLEST_STEP, -3_i32) => Dec19x19::MIN,
+/// });
+/// ```
+impl SaturatingPow<i32> for Dec19x19 {
+    type Output = Self;
+    #[track_caller]
+    #[inline(always)]
+    fn saturating_pow(self, exp: i32) -> Self::Output {
+        let saturated = if self.repr < 0 && exp % 2 != 0 { Self::MIN } else { Self::MAX };
+        self.checked_pow(exp).unwrap_or(saturated)
+    }
+}
+
+// =========================
+// === Degrees / Radians ===
+// =========================
+
+// π * 10^36. The full 10^38 scale is not used, so that `MAX * 180 * 10^36` still fits in `i256`.
+const PI_E36_I128: i128 = 3_141_592_653_589_793_238_462_643_383_279_502_884;
+const PI_SCALE_I128: i128 = crate::i128_ops::POW10[36];
+
+/// Divides `num` by a positive `den`, rounding half away from zero.
+#[inline(always)]
+fn div_round_i256(num: i256, den: i256) -> i256 {
+    let half = den / I256_TWO;
+    if num >= i256::ZERO { (num + half) / den } else { (num - half) / den }
+}
+
+/// Both conversions use `π` with 37 significant digits and round the result to the nearest
+/// representable value. For results below `10^18` in magnitude, the error is at most one unit in
+/// the last place.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// check! ( [Dec19x19::to_degrees] {
+///     (Dec19x19!(0)) => Dec19x19!(0),
+///     (Dec19x19::PI) => Dec19x19!(180.000_000_000_000_000_002_1),
+///     (Dec19x19!(1)) => Dec19x19!(57.295_779_513_082_320_876_8),
+///     (-Dec19x19::PI / Dec19x19!(2)) => Dec19x19!(-89.999_999_999_999_999_998_2),
+/// });
+/// check! ( [Dec19x19::to_radians] {
+///     (Dec19x19!(0)) => Dec19x19!(0),
+///     (Dec19x19!(180)) => Dec19x19::PI,
+///     (Dec19x19!(90)) => Dec19x19!(1.570_796_326_794_896_619_2),
+///     (Dec19x19!(-45)) => Dec19x19!(-0.785_398_163_397_448_309_6),
+///     (Dec19x19!(1)) => Dec19x19!(0.017_453_292_519_943_295_8),
+///     (Dec19x19::MAX) => Dec19x19!(296_952_384_462_490_763.294_355_659_211_630_773_1),
+/// });
+///
+/// // Round trips stay within a few units in the last place.
+/// for t in [Dec19x19!(1), Dec19x19!(-720.5), Dec19x19!(1e15), Dec19x19::SMALLEST_STEP] {
+///     let err = (t.to_radians().to_degrees() - t).abs();
+///     assert!(err <= Dec19x19::SMALLEST_STEP * 100_i64, "{t}: {err}");
+/// }
+/// ```
+impl Dec19x19 {
+    /// Converts an angle from radians to degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows and overflow checks are enabled.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn to_degrees(self) -> Self {
+        let half_turn = i256_from_i128(180) * i256_from_i128(PI_SCALE_I128);
+        let num = i256_from_i128(self.repr) * half_turn;
+        let result = div_round_i256(num, i256_from_i128(PI_E36_I128));
+        #[cfg(inherit_overflow_checks)]
+        { Self::from_repr(i256_to_i128(result).expect("Overflow in Dec19x19::to_degrees")) }
+        #[cfg(not(inherit_overflow_checks))]
+        { Self::from_repr(result.as_i128()) }
+    }
+
+    /// Converts an angle from degrees to radians. The result always fits, as `π / 180 < 1`.
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn to_radians(self) -> Self {
+        let half_turn = i256_from_i128(180) * i256_from_i128(PI_SCALE_I128);
+        let num = i256_from_i128(self.repr) * i256_from_i128(PI_E36_I128);
+        let result = div_round_i256(num, half_turn);
+        Self::from_repr(result.as_i128())
+    }
+}
+
+// ==================
+// === Cumulative ===
+// ==================
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// let values = [Dec19x19!(1.5), Dec19x19!(-2), Dec19x19!(0.25), Dec19x19!(4)];
+/// let manual_sum = values.iter().scan(Dec19x19!(0), |acc, t| { *acc += *t; Some(*acc) });
+/// let manual_prod = values.iter().scan(Dec19x19!(1), |acc, t| { *acc *= *t; Some(*acc) });
+/// assert_eq!(Dec19x19::cumsum(values).collect::<Vec<_>>(), manual_sum.collect::<Vec<_>>());
+/// assert_eq!(Dec19x19::cumprod(values).collect::<Vec<_>>(), manual_prod.collect::<Vec<_>>());
+/// assert_eq!(
+///     Dec19x19::cumsum(values).collect::<Vec<_>>(),
+///     [Dec19x19!(1.5), Dec19x19!(-0.5), Dec19x19!(-0.25), Dec19x19!(3.75)]
+/// );
+/// assert_eq!(
+///     Dec19x19::cumprod(values).collect::<Vec<_>>(),
+///     [Dec19x19!(1.5), Dec19x19!(-3), Dec19x19!(-0.75), Dec19x19!(-3)]
+/// );
+/// assert_eq!(Dec19x19::cumsum([]).count(), 0);
+///
+/// // Checked variants yield `None` once on overflow and stop afterwards.
+/// assert_eq!(
+///     Dec19x19::checked_cumsum(values).collect::<Vec<_>>(),
+///     Dec19x19::cumsum(values).map(Some).collect::<Vec<_>>()
+/// );
+/// assert_eq!(
+///     Dec19x19::checked_cumsum([Dec19x19!(1), Dec19x19::MAX, Dec19x19!(1)]).collect::<Vec<_>>(),
+///     [Some(Dec19x19!(1)), None]
+/// );
+/// assert_eq!(
+///     Dec19x19::checked_cumprod([Dec19x19!(2), Dec19x19::MAX, Dec19x19!(1)]).collect::<Vec<_>>(),
+///     [Some(Dec19x19!(2)), None]
+/// );
+///
+/// // The unchecked variants panic lazily, when the overflowing item is reached.
+/// let mut sums = Dec19x19::cumsum([Dec19x19!(1), Dec19x19::MAX]);
+/// assert_eq!(sums.next(), Some(Dec19x19!(1)));
+/// let msg = |r: std::thread::Result<_>| r.unwrap_err().downcast_ref::<String>().cloned();
+/// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sums.next()));
+/// assert_eq!(msg(result).as_deref(), Some("Cumulative sum overflow"));
+/// let mut prods = Dec19x19::cumprod([Dec19x19!(2), Dec19x19::MAX]);
+/// assert_eq!(prods.next(), Some(Dec19x19!(2)));
+/// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| prods.next()));
+/// assert_eq!(msg(result).as_deref(), Some("Cumulative product overflow"));
+/// ```
+impl Dec19x19 {
+    /// Running sum of the values.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `Cumulative sum overflow` if the sum overflows, in every build profile, like
+    /// [`Sum`](std::iter::Sum). Use [`Self::checked_cumsum`] to handle overflow.
+    pub fn cumsum(iter: impl IntoIterator<Item = Self>) -> impl Iterator<Item = Self> {
+        iter.into_iter().scan(Dec19x19!(0), |acc, t| {
+            *acc = acc.checked_add(t).expect("Cumulative sum overflow");
+            Some(*acc)
+        })
+    }
+
+    /// Running product of the values.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `Cumulative product overflow` if the product overflows, in every build
+    /// profile, like [`Product`](std::iter::Product). Use [`Self::checked_cumprod`] to handle
+    /// overflow.
+    pub fn cumprod(iter: impl IntoIterator<Item = Self>) -> impl Iterator<Item = Self> {
+        iter.into_iter().scan(Dec19x19!(1), |acc, t| {
+            *acc = acc.checked_mul(t).expect("Cumulative product overflow");
+            Some(*acc)
+        })
+    }
+
+    /// ✅ Running sum of the values. Yields `None` once if the sum overflows and stops afterwards.
+    pub fn checked_cumsum(
+        iter: impl IntoIterator<Item = Self>
+    ) -> impl Iterator<Item = Option<Self>> {
+        iter.into_iter().scan(Some(Dec19x19!(0)), |acc, t| {
+            *acc = (*acc)?.checked_add(t);
+            Some(*acc)
+        })
+    }
+
+    /// ✅ Running product of the values. Yields `None` once if the product overflows and stops
+    /// afterwards.
+    pub fn checked_cumprod(
+        iter: impl IntoIterator<Item = Self>
+    ) -> impl Iterator<Item = Option<Self>> {
+        iter.into_iter().scan(Some(Dec19x19!(1)), |acc, t| {
+            *acc = (*acc)?.checked_mul(t);
+            Some(*acc)
+        })
+    }
+}
+
+// =======================
+// === Sum and Product ===
+// =======================
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// let values = [Dec19x19!(1.5), Dec19x19!(-2), Dec19x19!(0.25), Dec19x19!(4)];
+/// assert_eq!(values.iter().sum::<Dec19x19>(), Dec19x19!(3.75));
+/// assert_eq!(values.into_iter().sum::<Dec19x19>(), Dec19x19!(3.75));
+/// assert_eq!(values.iter().product::<Dec19x19>(), Dec19x19!(-3));
+/// assert_eq!(values.into_iter().product::<Dec19x19>(), Dec19x19!(-3));
+/// assert_eq!(std::iter::empty::<Dec19x19>().sum::<Dec19x19>(), Dec19x19!(0));
+/// assert_eq!(std::iter::empty::<Dec19x19>().product::<Dec19x19>(), Dec19x19!(1));
+///
+/// // Overflow panics with a message identifying the operation.
+/// let panic_message = |f: fn() -> Dec19x19| {
+///     let err = std::panic::catch_unwind(f).unwrap_err();
+///     err.downcast_ref::<String>().cloned().or(err.downcast_ref::<&str>().map(|t| t.to_string()))
+/// };
+/// assert_eq!(
+///     panic_message(|| [Dec19x19::MAX, Dec19x19!(1)].into_iter().sum()).as_deref(),
+///     Some("Sum overflow")
+/// );
+/// assert_eq!(
+///     panic_message(|| [Dec19x19::MAX, Dec19x19!(2)].iter().product()).as_deref(),
+///     Some("Product overflow")
+/// );
+///
+/// // Non-panicking alternatives.
+/// let checked_sum = |t: &[Dec19x19]| {
+///     t.iter().try_fold(Dec19x19!(0), |acc, t| acc.checked_add(*t))
+/// };
+/// assert_eq!(checked_sum(&values), Some(Dec19x19!(3.75)));
+/// assert_eq!(checked_sum(&[Dec19x19::MAX, Dec19x19!(1)]), None);
+/// ```
+impl std::iter::Sum for Dec19x19 {
+    /// Sums all values. To handle overflow without panicking, use
+    /// `try_fold(Dec19x19!(0), |acc, t| acc.checked_add(t))` or [`Dec19x19::checked_cumsum`].
+    ///
+    /// # Panics
+    ///
+    /// Panics with `Sum overflow` if the sum overflows.
+    #[track_caller]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        // A loop instead of `fold`, so the panic location points to the caller.
+        let mut acc = Dec19x19!(0);
+        for t in iter {
+            acc = acc.checked_add(t).expect("Sum overflow");
+        }
+        acc
+    }
+}
+
+impl<'t> std::iter::Sum<&'t Self> for Dec19x19 {
+    /// See the [`Sum`](std::iter::Sum) implementation for owned values.
+    #[track_caller]
+    fn sum<I: Iterator<Item = &'t Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl std::iter::Product for Dec19x19 {
+    /// Multiplies all values. To handle overflow without panicking, use
+    /// `try_fold(Dec19x19!(1), |acc, t| acc.checked_mul(t))` or [`Dec19x19::checked_cumprod`].
+    ///
+    /// # Panics
+    ///
+    /// Panics with `Product overflow` if the product overflows.
+    #[track_caller]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut acc = Dec19x19!(1);
+        for t in iter {
+            acc = acc.checked_mul(t).expect("Product overflow");
+        }
+        acc
+    }
+}
+
+impl<'t> std::iter::Product<&'t Self> for Dec19x19 {
+    /// See the [`Product`](std::iter::Product) implementation for owned values.
+    #[track_caller]
+    fn product<I: Iterator<Item = &'t Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl Dec19x19 {
+    /// Sums all values, reporting where the sum overflowed. On overflow, returns the index of the
+    /// item that could not be added and the running total of all items before it.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let values = [Dec19x19!(1.5), Dec19x19!(-2), Dec19x19!(0.25), Dec19x19!(4)];
+    /// assert_eq!(Dec19x19::sum_reporting(values.into_iter()), Ok(Dec19x19!(3.75)));
+    /// assert_eq!(Dec19x19::sum_reporting(std::iter::empty()), Ok(Dec19x19!(0)));
+    ///
+    /// let half = Dec19x19::MAX / Dec19x19!(2);
+    /// let values = [Dec19x19!(1), half, Dec19x19!(-1), half, Dec19x19!(1), Dec19x19!(1)];
+    /// assert_eq!(
+    ///     Dec19x19::sum_reporting(values.into_iter()),
+    ///     Err((4, half + half))
+    /// );
+    /// assert_eq!(
+    ///     Dec19x19::sum_reporting([Dec19x19::MIN, -Dec19x19::SMALLEST_STEP].into_iter()),
+    ///     Err((1, Dec19x19::MIN))
+    /// );
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    pub fn sum_reporting(iter: impl Iterator<Item = Self>) -> Result<Self, (usize, Self)> {
+        let mut acc = Dec19x19!(0);
+        for (index, t) in iter.enumerate() {
+            acc = acc.checked_add(t).ok_or((index, acc))?;
+        }
+        Ok(acc)
+    }
+}
+
+/// Error returned by [`Dec19x19::try_sum`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TrySumError<E> {
+    /// An item of the iterator was an error.
+    Item(E),
+    /// The sum overflowed.
+    Overflow,
+}
+
+impl<E: std::error::Error> std::error::Error for TrySumError<E> {}
+impl<E: std::fmt::Display> std::fmt::Display for TrySumError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Item(err) => write!(f, "{err}"),
+            Self::Overflow => write!(f, "Sum overflow"),
+        }
+    }
+}
+
+impl Dec19x19 {
+    /// Sums fallible values, stopping at the first error or at the first overflow, whichever
+    /// comes first.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::TrySumError;
+    /// use std::str::FromStr;
+    /// let sum = |t: &[&str]| Dec19x19::try_sum(t.iter().map(|t| Dec19x19::from_str(t)));
+    /// assert_eq!(sum(&["1.5", "-2", "0.25"]), Ok(Dec19x19!(-0.25)));
+    /// assert_eq!(sum(&[]), Ok(Dec19x19!(0)));
+    /// assert!(matches!(sum(&["1.5", "abc", "2"]), Err(TrySumError::Item(_))));
+    ///
+    /// let max = Dec19x19::MAX.to_string();
+    /// assert_eq!(sum(&[&max, "1", "abc"]), Err(TrySumError::Overflow));
+    /// assert!(matches!(sum(&[&max, "abc", "1"]), Err(TrySumError::Item(_))));
+    ///
+    /// let err = Dec19x19::try_sum([Ok(Dec19x19::MAX), Err("bad"), Ok(Dec19x19!(1))]);
+    /// assert_eq!(err, Err(TrySumError::Item("bad")));
+    /// assert_eq!(TrySumError::<&str>::Overflow.to_string(), "Sum overflow");
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    pub fn try_sum<E>(
+        iter: impl IntoIterator<Item = Result<Self, E>>
+    ) -> Result<Self, TrySumError<E>> {
+        let mut acc = Dec19x19!(0);
+        for t in iter {
+            let t = t.map_err(TrySumError::Item)?;
+            acc = acc.checked_add(t).ok_or(TrySumError::Overflow)?;
+        }
+        Ok(acc)
+    }
+
+    /// Sums the values in `i256` and narrows the total once, so intermediate partial sums never
+    /// overflow. Returns `None` only if the final total is out of range, regardless of the order
+    /// of the values.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let values = [Dec19x19!(1.5), Dec19x19!(-2), Dec19x19!(0.25), Dec19x19!(4)];
+    /// assert_eq!(Dec19x19::checked_sum_slice(&values), Some(Dec19x19!(3.75)));
+    /// assert_eq!(Dec19x19::checked_sum_slice(&[]), Some(Dec19x19!(0)));
+    ///
+    /// // A `checked_add` fold fails on the first order, as `MAX + 1` overflows, but not on the
+    /// // second one, even though both have the same total.
+    /// let values = [Dec19x19::MAX, Dec19x19!(1), Dec19x19!(-1)];
+    /// let rearranged = [Dec19x19::MAX, Dec19x19!(-1), Dec19x19!(1)];
+    /// let fold = |t: &[Dec19x19]| t.iter().try_fold(Dec19x19!(0), |acc, t| acc.checked_add(*t));
+    /// assert_eq!(fold(&values), None);
+    /// assert_eq!(fold(&rearranged), Some(Dec19x19::MAX));
+    /// assert_eq!(Dec19x19::checked_sum_slice(&values), Some(Dec19x19::MAX));
+    /// assert_eq!(Dec19x19::checked_sum_slice(&rearranged), Some(Dec19x19::MAX));
+    ///
+    /// let values = [Dec19x19::MIN, Dec19x19::MIN, Dec19x19::MAX, Dec19x19::MAX];
+    /// assert_eq!(Dec19x19::checked_sum_slice(&values), Some(-Dec19x19::SMALLEST_STEP * 2));
+    /// assert_eq!(Dec19x19::checked_sum_slice(&[Dec19x19::MAX, Dec19x19::SMALLEST_STEP]), None);
+    /// assert_eq!(Dec19x19::checked_sum_slice(&[Dec19x19::MIN, -Dec19x19::SMALLEST_STEP]), None);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_sum_slice(values: &[Self]) -> Option<Self> {
+        let total = values.iter().fold(i256::ZERO, |acc, t| acc + i256_from_i128(t.repr));
+        i256_to_i128(total).map(Self::from_repr)
+    }
+}
+
+/// Sum of values collected from an iterator. Allows writing
+/// `let total: Total = values.into_iter().collect()` in pipeline-style code.
+///
+/// # Panics
+///
+/// Collecting panics with `Sum overflow` if the sum overflows, the same way [`Sum`] does.
+///
+/// [`Sum`]: std::iter::Sum
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// use fixed_num::dec19x19::Total;
+/// let values = vec![Dec19x19!(1.5), Dec19x19!(-2), Dec19x19!(0.25), Dec19x19!(4)];
+/// let total: Total = values.iter().collect();
+/// assert_eq!(total, Total(Dec19x19!(3.75)));
+/// let total: Total = values.into_iter().collect();
+/// assert_eq!(total.0, Dec19x19!(3.75));
+/// let total: Total = std::iter::empty::<Dec19x19>().collect();
+/// assert_eq!(total, Total(Dec19x19!(0)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Total(pub Dec19x19);
+
+impl FromIterator<Dec19x19> for Total {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = Dec19x19>>(iter: I) -> Self {
+        Self(iter.into_iter().sum())
+    }
+}
+
+impl<'t> FromIterator<&'t Dec19x19> for Total {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = &'t Dec19x19>>(iter: I) -> Self {
+        Self(iter.into_iter().sum())
+    }
+}
+
+// =============
+// === Batch ===
+// =============
+
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// let a = series_pair1::<Dec19x19, BigDecimal>(Series::new(0..=18, 0..=19));
+/// let b_series = Series { seed: 17, ..Series::new(0..=18, 0..=19) };
+/// let b = series_pair1::<Dec19x19, BigDecimal>(b_series);
+/// let a = a.into_iter().map(|t| t.0).collect::<Vec<_>>();
+/// let b = b.into_iter().map(|t| t.0).collect::<Vec<_>>();
+/// let mut out = vec![Dec19x19!(0); a.len()];
+/// Dec19x19::add_slices(&a, &b, &mut out);
+/// let expected = a.iter().zip(&b).map(|(a, b)| a + b).collect::<Vec<_>>();
+/// assert_eq!(out, expected);
+///
+/// should_panic(|| Dec19x19::add_slices(&a[..2], &b[..2], &mut [Dec19x19!(0)]), "length mismatch");
+/// let overflowing = [Dec19x19!(1), Dec19x19::MAX];
+/// let mut out = [Dec19x19!(0); 2];
+/// should_panic(move || Dec19x19::add_slices(&overflowing, &overflowing, &mut out), "overflow");
+/// ```
+impl Dec19x19 {
+    /// Element-wise addition of two slices, writing the results to `out`.
+    ///
+    /// By default, the elements are added one by one with [`Add`]. With the `batch_overflow_check`
+    /// feature enabled, they are added with [`i128::overflowing_add`] instead, which removes the
+    /// per-element panic branch, and overflow is checked once after the whole batch is
+    /// processed. This only changes the generated code when overflow checks are enabled, as
+    /// otherwise [`Add`] wraps without branching as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths. Overflow is handled the same way as in
+    /// [`Add`], so it panics if overflow checks are enabled. With the `batch_overflow_check`
+    /// feature enabled, `out` contains wrapped results for all elements in such a case.
+    #[track_caller]
+    pub fn add_slices(a: &[Self], b: &[Self], out: &mut [Self]) {
+        assert!(a.len() == b.len() && a.len() == out.len(), "Slice lengths differ");
+        #[cfg(feature = "batch_overflow_check")]
+        {
+            let mut overflow = false;
+            for ((a, b), out) in a.iter().zip(b).zip(out.iter_mut()) {
+                let (repr, elem_overflow) = a.repr.overflowing_add(b.repr);
+                overflow |= elem_overflow;
+                out.repr = repr;
+            }
+            assert!(!(cfg!(inherit_overflow_checks) && overflow), "Overflow in batch addition");
+        }
+        #[cfg(not(feature = "batch_overflow_check"))]
+        for ((a, b), out) in a.iter().zip(b).zip(out.iter_mut()) {
+            *out = *a + *b;
+        }
+    }
+}
+
+// ==================
+// === Statistics ===
+// ==================
+
+impl Dec19x19 {
+    /// ✅ The arithmetic mean of the values, or `None` if the slice is empty. The sum is
+    /// accumulated using 256-bit integers, so it never overflows. The result is truncated toward
+    /// zero.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19::mean(&[]), None);
+    /// assert_eq!(Dec19x19::mean(&[Dec19x19!(3.5)]), Some(Dec19x19!(3.5)));
+    /// assert_eq!(Dec19x19::mean(&[Dec19x19!(1), Dec19x19!(2), Dec19x19!(4)]),
+    ///     Some(Dec19x19!(2.333_333_333_333_333_333_3)));
+    /// assert_eq!(Dec19x19::mean(&[Dec19x19!(-1), Dec19x19!(-2), Dec19x19!(-4)]),
+    ///     Some(Dec19x19!(-2.333_333_333_333_333_333_3)));
+    ///
+    /// // The naive sum would overflow.
+    /// assert_eq!(Dec19x19::mean(&[Dec19x19::MAX, Dec19x19::MAX]), Some(Dec19x19::MAX));
+    /// assert_eq!(Dec19x19::mean(&[Dec19x19::MIN; 3]), Some(Dec19x19::MIN));
+    /// assert_eq!(
+    ///     Dec19x19::mean(&[Dec19x19::MAX, Dec19x19::MAX, Dec19x19::MIN]),
+    ///     Some(Dec19x19!(5_671_372_782_015_641_057.722_910_123_862_803_524_2))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn mean(values: &[Self]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let sum = values.iter().fold(i256_from_i128(0), |acc, t| acc + i256_from_i128(t.repr));
+        let count = i256_from_i128(values.len() as i128);
+        i256_to_i128(sum / count).map(Self::from_repr)
+    }
+
+    /// ✅ The population variance of the values (the mean of squared deviations from the mean),
+    /// or `None` if the slice is empty or the result does not fit in [`Dec19x19`]. Computed using
+    /// 256-bit integers, so no precision is lost in intermediate steps. The result is truncated.
+    /// Use [`Self::sample_variance`] to get the unbiased sample variance instead.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let values = [2, 4, 4, 4, 5, 5, 7, 9].map(Dec19x19::from);
+    /// assert_eq!(Dec19x19::variance(&values), Some(Dec19x19!(4)));
+    /// let sample_variance = Dec19x19!(4.571_428_571_428_571_428_5);
+    /// assert_eq!(Dec19x19::sample_variance(&values), Some(sample_variance));
+    ///
+    /// let values = [Dec19x19!(1.5), Dec19x19!(-0.5), Dec19x19!(2.25)];
+    /// assert_eq!(Dec19x19::variance(&values), Some(Dec19x19!(1.347_222_222_222_222_222_2)));
+    /// let sample_variance = Dec19x19!(2.020_833_333_333_333_333_3);
+    /// assert_eq!(Dec19x19::sample_variance(&values), Some(sample_variance));
+    ///
+    /// assert_eq!(Dec19x19::variance(&[Dec19x19!(3); 5]), Some(Dec19x19!(0)));
+    /// assert_eq!(Dec19x19::variance(&[Dec19x19::MAX; 5]), Some(Dec19x19!(0)));
+    /// assert_eq!(Dec19x19::variance(&[Dec19x19!(3)]), Some(Dec19x19!(0)));
+    /// assert_eq!(Dec19x19::variance(&[]), None);
+    /// assert_eq!(Dec19x19::sample_variance(&[Dec19x19!(3)]), None);
+    /// assert_eq!(Dec19x19::variance(&[Dec19x19::MAX, Dec19x19::MIN]), None);
+    /// ```
+    #[must_use]
+    pub fn variance(values: &[Self]) -> Option<Self> {
+        let (sum_sq_dev, _) = Self::sum_sq_dev(values)?;
+        let count = i256_from_i128(values.len() as i128);
+        i256_to_i128(sum_sq_dev / FRAC_SCALE_I256 / count).map(Self::from_repr)
+    }
+
+    /// ✅ The sample variance of the values (the sum of squared deviations from the mean divided
+    /// by `n - 1`), or `None` if the slice has fewer than two elements or the result does not
+    /// fit in [`Dec19x19`]. See [`Self::variance`] to learn more.
+    #[must_use]
+    pub fn sample_variance(values: &[Self]) -> Option<Self> {
+        if values.len() < 2 {
+            return None;
+        }
+        let (sum_sq_dev, _) = Self::sum_sq_dev(values)?;
+        let count = i256_from_i128(values.len() as i128 - 1);
+        i256_to_i128(sum_sq_dev / FRAC_SCALE_I256 / count).map(Self::from_repr)
+    }
+
+    /// ✅ The population standard deviation of the values, or `None` if the slice is empty or the
+    /// result does not fit in [`Dec19x19`]. The square root is computed from the exact 256-bit sum
+    /// of squared deviations, so it is more precise than the square root of [`Self::variance`].
+    /// The result is truncated.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let values = [2, 4, 4, 4, 5, 5, 7, 9].map(Dec19x19::from);
+    /// assert_eq!(Dec19x19::stddev(&values), Some(Dec19x19!(2)));
+    /// assert_eq!(Dec19x19::sample_stddev(&values), Some(Dec19x19!(2.138_089_935_299_395_077_4)));
+    ///
+    /// assert_eq!(Dec19x19::stddev(&[Dec19x19!(3); 5]), Some(Dec19x19!(0)));
+    /// assert_eq!(
+    ///     Dec19x19::stddev(&[Dec19x19::MAX, Dec19x19::MIN]),
+    ///     Some(Dec19x19::MAX)
+    /// );
+    /// assert_eq!(Dec19x19::stddev(&[]), None);
+    /// assert_eq!(Dec19x19::sample_stddev(&[Dec19x19!(3)]), None);
+    /// assert_eq!(Dec19x19::sample_stddev(&[Dec19x19::MAX, Dec19x19::MIN]), None);
+    /// ```
+    ///
+    /// # Fuzzy
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// let series = series_pair1::<Dec19x19, BigDecimal>(Series::new(0..=8, 0..=19));
+    /// for chunk in series.chunks(5) {
+    ///     let (fs, bs): (Vec<Dec19x19>, Vec<BigDecimal>) = chunk.iter().cloned().unzip();
+    ///     let count = BigDecimal::from(bs.len() as u64);
+    ///     let mean = bs.iter().sum::<BigDecimal>() / &count;
+    ///     let sum_sq_dev = bs.iter().map(|b| (b - &mean).square()).sum::<BigDecimal>();
+    ///     should_eq(Dec19x19::stddev(&fs).unwrap(), (&sum_sq_dev / &count).sqrt().unwrap());
+    ///     let sample_count = count - BigDecimal::from(1);
+    ///     let sample_stddev = (sum_sq_dev / sample_count).sqrt().unwrap();
+    ///     should_eq(Dec19x19::sample_stddev(&fs).unwrap(), sample_stddev);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn stddev(values: &[Self]) -> Option<Self> {
+        let (sum_sq_dev, max_dev) = Self::sum_sq_dev(values)?;
+        let count = i256_from_i128(values.len() as i128);
+        Self::sqrt_from_sum_sq_dev(sum_sq_dev / count, max_dev)
+    }
+
+    /// ✅ The sample standard deviation of the values, or `None` if the slice has fewer than two
+    /// elements or the result does not fit in [`Dec19x19`]. See [`Self::stddev`] to learn more.
+    #[must_use]
+    pub fn sample_stddev(values: &[Self]) -> Option<Self> {
+        if values.len() < 2 {
+            return None;
+        }
+        let (sum_sq_dev, max_dev) = Self::sum_sq_dev(values)?;
+        let count = i256_from_i128(values.len() as i128 - 1);
+        // For `n >= 2`, `sqrt(n / (n - 1)) <= 2`, so the doubled max deviation is above the root.
+        Self::sqrt_from_sum_sq_dev(sum_sq_dev / count, max_dev * I256_TWO)
+    }
+
+    /// ✅ The geometric mean of the values, computed as the exponent of the mean of their
+    /// logarithms. Returns `None` if the slice is empty or contains a non-positive value. As
+    /// [`CheckedLn::checked_ln`] and [`CheckedExp::checked_exp`] are not exact, the relative
+    /// error of the result is in the order of `10^-18`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let eps = Dec19x19!(1e-17);
+    /// let geometric_mean = |t: &[Dec19x19]| Dec19x19::geometric_mean(t).unwrap();
+    /// assert!(geometric_mean(&[Dec19x19!(3.5)]).approx_eq(Dec19x19!(3.5), eps));
+    /// assert!(geometric_mean(&[Dec19x19!(2), Dec19x19!(8)]).approx_eq(Dec19x19!(4), eps));
+    /// assert!(geometric_mean(&[Dec19x19!(1), Dec19x19!(2), Dec19x19!(4)])
+    ///     .approx_eq(Dec19x19!(2), eps));
+    /// assert!(geometric_mean(&[Dec19x19!(1e9), Dec19x19!(1e-9)]).approx_eq(Dec19x19!(1), eps));
+    /// assert!(geometric_mean(&[Dec19x19!(1e18); 2]).approx_eq(Dec19x19!(1e18), Dec19x19!(1)));
+    /// // Average growth factor of yearly returns of 10%, -20% and 30%.
+    /// assert!(
+    ///     geometric_mean(&[Dec19x19!(1.1), Dec19x19!(0.8), Dec19x19!(1.3)])
+    ///         .approx_eq(Dec19x19!(1.045_864_306_351_196_693_9), eps)
+    /// );
+    /// assert_eq!(Dec19x19::geometric_mean(&[]), None);
+    /// assert_eq!(Dec19x19::geometric_mean(&[Dec19x19!(2), Dec19x19!(0)]), None);
+    /// assert_eq!(Dec19x19::geometric_mean(&[Dec19x19!(2), Dec19x19!(-8)]), None);
+    /// ```
+    #[must_use]
+    pub fn geometric_mean(values: &[Self]) -> Option<Self> {
+        let logs = values.iter().map(|t| t.checked_ln()).collect::<Option<Vec<_>>>()?;
+        Self::mean(&logs)?.checked_exp()
+    }
+
+    /// ✅ The harmonic mean of the values, the count divided by the sum of reciprocals. Returns
+    /// `None` if the slice is empty, contains zero, the reciprocals sum to zero, or the result
+    /// does not fit in [`Dec19x19`].
+    ///
+    /// The reciprocals are summed in 256-bit integers with over 50 extra fractional digits, and
+    /// the result is rounded to the nearest representable value. It is exact up to this rounding
+    /// for values below `10^15` in magnitude, while for values close to the bounds the last
+    /// digits may be off.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let harmonic_mean = |t: &[Dec19x19]| Dec19x19::harmonic_mean(t);
+    /// assert_eq!(harmonic_mean(&[]), None);
+    /// assert_eq!(harmonic_mean(&[Dec19x19!(3.5)]), Some(Dec19x19!(3.5)));
+    /// assert_eq!(harmonic_mean(&[Dec19x19!(1), Dec19x19!(4), Dec19x19!(4)]), Some(Dec19x19!(2)));
+    /// // Average speed over two equal distances driven at 40 and 60.
+    /// assert_eq!(harmonic_mean(&[Dec19x19!(40), Dec19x19!(60)]), Some(Dec19x19!(48)));
+    /// assert_eq!(harmonic_mean(&[Dec19x19!(-40), Dec19x19!(-60)]), Some(Dec19x19!(-48)));
+    /// assert_eq!(
+    ///     harmonic_mean(&[Dec19x19!(1), Dec19x19!(2), Dec19x19!(3)]),
+    ///     Some(Dec19x19!(1.636_363_636_363_636_363_6))
+    /// );
+    /// assert_eq!(
+    ///     harmonic_mean(&[Dec19x19!(1), Dec19x19!(2), Dec19x19!(-3)]),
+    ///     Some(Dec19x19!(2.571_428_571_428_571_428_6))
+    /// );
+    /// assert_eq!(
+    ///     harmonic_mean(&[Dec19x19!(1e14), Dec19x19!(3e14)]),
+    ///     Some(Dec19x19!(150_000_000_000_000))
+    /// );
+    /// assert_eq!(
+    ///     harmonic_mean(&[Dec19x19::SMALLEST_STEP; 2]),
+    ///     Some(Dec19x19::SMALLEST_STEP)
+    /// );
+    /// let max = harmonic_mean(&[Dec19x19::MAX, Dec19x19::MAX / Dec19x19!(2)]).unwrap();
+    /// assert!(max.approx_eq(Dec19x19::MAX / Dec19x19!(1.5), Dec19x19!(1e-17)));
+    /// assert_eq!(harmonic_mean(&[Dec19x19!(2), Dec19x19!(0)]), None);
+    /// assert_eq!(harmonic_mean(&[Dec19x19!(2), Dec19x19!(-2)]), None);
+    /// ```
+    #[must_use]
+    pub fn harmonic_mean(values: &[Self]) -> Option<Self> {
+        use crate::i128_ops::POW10;
+        // The largest power of 10 that keeps `count * scale` within `i256`. Each reciprocal is
+        // `scale / repr`, so neither it nor the sum of reciprocals can overflow either.
+        let count_digits = values.len().checked_ilog10().unwrap_or(0) as usize + 1;
+        let scale = i256_from_i128(POW10[38]) * i256_from_i128(POW10[38 - count_digits]);
+        let mut sum_recip = i256::ZERO;
+        for t in values {
+            if t.repr == 0 {
+                return None;
+            }
+            let recip = div_round_i256(scale, i256_from_i128(t.repr).wrapping_abs());
+            sum_recip = if t.repr < 0 { sum_recip - recip } else { sum_recip + recip };
+        }
+        if sum_recip == i256::ZERO {
+            return None;
+        }
+        let count_scale = i256_from_i128(values.len() as i128) * scale;
+        let result = if sum_recip < i256::ZERO {
+            div_round_i256(-count_scale, -sum_recip)
+        } else {
+            div_round_i256(count_scale, sum_recip)
+        };
+        i256_to_i128(result).map(Self::from_repr)
+    }
+
+    /// ✅ The weighted arithmetic mean of `(value, weight)` pairs, `Σ(v * w) / Σw`. Both sums are
+    /// accumulated exactly using 256-bit integers and the result is truncated toward zero.
+    /// Returns `None` if the total weight is zero (including for an empty slice), or the result
+    /// does not fit in [`Dec19x19`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let pairs = [(Dec19x19!(10), Dec19x19!(1)), (Dec19x19!(20), Dec19x19!(3))];
+    /// assert_eq!(Dec19x19::weighted_average(&pairs), Some(Dec19x19!(17.5)));
+    /// // Portfolio return: 60% at 5%, 30% at -2%, 10% at 12%.
+    /// let pairs = [
+    ///     (Dec19x19!(0.05), Dec19x19!(0.6)),
+    ///     (Dec19x19!(-0.02), Dec19x19!(0.3)),
+    ///     (Dec19x19!(0.12), Dec19x19!(0.1)),
+    /// ];
+    /// assert_eq!(Dec19x19::weighted_average(&pairs), Some(Dec19x19!(0.036)));
+    /// let pairs = [(Dec19x19!(1), Dec19x19!(1)), (Dec19x19!(2), Dec19x19!(2))];
+    /// let expected = Dec19x19!(1.666_666_666_666_666_666_6);
+    /// assert_eq!(Dec19x19::weighted_average(&pairs), Some(expected));
+    /// // Zero weights do not contribute.
+    /// let pairs = [(Dec19x19!(10), Dec19x19!(2)), (Dec19x19!(1000), Dec19x19!(0))];
+    /// assert_eq!(Dec19x19::weighted_average(&pairs), Some(Dec19x19!(10)));
+    /// // The naive sum of products would overflow.
+    /// let pairs = [(Dec19x19::MAX, Dec19x19!(1e6)), (Dec19x19::MAX, Dec19x19!(3e6))];
+    /// assert_eq!(Dec19x19::weighted_average(&pairs), Some(Dec19x19::MAX));
+    ///
+    /// assert_eq!(Dec19x19::weighted_average(&[]), None);
+    /// assert_eq!(Dec19x19::weighted_average(&[(Dec19x19!(10), Dec19x19!(0))]), None);
+    /// let pairs = [(Dec19x19!(10), Dec19x19!(1)), (Dec19x19!(20), Dec19x19!(-1))];
+    /// assert_eq!(Dec19x19::weighted_average(&pairs), None);
+    /// let pairs = [(Dec19x19::MAX, Dec19x19!(2)), (Dec19x19!(0), Dec19x19!(-1))];
+    /// assert_eq!(Dec19x19::weighted_average(&pairs), None);
+    /// ```
+    #[must_use]
+    pub fn weighted_average(pairs: &[(Self, Self)]) -> Option<Self> {
+        let mut sum_weighted = i256::ZERO;
+        let mut sum_weights = i256::ZERO;
+        for (value, weight) in pairs {
+            let weight = i256_from_i128(weight.repr);
+            sum_weighted = sum_weighted.checked_add(i256_from_i128(value.repr) * weight)?;
+            sum_weights += weight;
+        }
+        // `Σ(v * w)` is scaled by `10^38` and `Σw` by `10^19`, so the quotient is a repr.
+        i256_to_i128(sum_weighted.checked_div(sum_weights)?).map(Self::from_repr)
+    }
+
+    /// The sum of squared deviations from the mean, in squared repr units, and the maximum
+    /// absolute deviation, in repr units. Returns `None` for an empty slice or on overflow.
+    fn sum_sq_dev(values: &[Self]) -> Option<(i256, i256)> {
+        let mean = i256_from_i128(Self::mean(values)?.repr);
+        let mut sum_sq_dev = i256_from_i128(0);
+        let mut max_dev = i256_from_i128(0);
+        for t in values {
+            let dev = (i256_from_i128(t.repr) - mean).wrapping_abs();
+            sum_sq_dev = sum_sq_dev.checked_add(dev.checked_mul(dev)?)?;
+            max_dev = max_dev.max(dev);
+        }
+        Some((sum_sq_dev, max_dev))
+    }
+
+    /// Square root of the mean squared deviation, which is equal to the repr of the result, as
+    /// `sqrt(d² / n) = sqrt(d²) / sqrt(n)`, where `d` is already scaled by `10^19`.
+    fn sqrt_from_sum_sq_dev(mean_sq_dev: i256, guess: i256) -> Option<Self> {
+        if mean_sq_dev == i256_from_i128(0) {
+            return Some(Dec19x19!(0));
+        }
+        i256_to_i128(isqrt_i256_from_above(mean_sq_dev, guess)).map(Self::from_repr)
+    }
+}
+
+// =====================
+// === Interpolation ===
+// =====================
+
+impl Dec19x19 {
+    /// Linear interpolation between `self` (for `t = 0`) and `end` (for `t = 1`). The result is
+    /// computed exactly in `i256` and truncated, so it never overflows for `t` in `0 ..= 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows (possible only for `t` outside of `0 ..= 1`) and overflow
+    /// checks are enabled.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::lerp] {
+    ///     (Dec19x19!(10), Dec19x19!(20), Dec19x19!(0)) => Dec19x19!(10),
+    ///     (Dec19x19!(10), Dec19x19!(20), Dec19x19!(1)) => Dec19x19!(20),
+    ///     (Dec19x19!(10), Dec19x19!(20), Dec19x19!(0.25)) => Dec19x19!(12.5),
+    ///     (Dec19x19!(20), Dec19x19!(10), Dec19x19!(0.25)) => Dec19x19!(17.5),
+    ///     (Dec19x19!(10), Dec19x19!(20), Dec19x19!(1.5)) => Dec19x19!(25),
+    ///     (Dec19x19!(10), Dec19x19!(20), Dec19x19!(-1)) => Dec19x19!(0),
+    ///     (Dec19x19::MIN, Dec19x19::MAX, Dec19x19!(0.5)) => -Dec19x19::SMALLEST_STEP,
+    ///     (Dec19x19::MIN, Dec19x19::MAX, Dec19x19!(1)) => Dec19x19::MAX,
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub fn lerp(self, end: Self, t: Self) -> Self {
+        let start = i256_from_i128(self.repr);
+        let delta = i256_from_i128(end.repr) - start;
+        let result = start + delta * i256_from_i128(t.repr) / FRAC_SCALE_I256;
+        #[cfg(inherit_overflow_checks)]
+        { Self::from_repr(i256_to_i128(result).expect("Overflow in Dec19x19::lerp")) }
+        #[cfg(not(inherit_overflow_checks))]
+        { Self::from_repr(result.as_i128()) }
     }
-}
 
-impl CheckedPow<i32> for Dec19x19 {
-    type Output = Self;
+    /// Piecewise linear interpolation of the table given by sorted `xs` and the matching `ys`.
+    /// Returns `None` if `x` is outside of `xs[0] ..= xs[xs.len() - 1]`, the slices are empty, or
+    /// their lengths differ. The value is not clamped to the table range, as silently
+    /// extrapolating a fee schedule is rarely intended. For duplicated `xs` entries (a step in
+    /// the table), the first matching `ys` entry is returned.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// let xs = [Dec19x19!(0), Dec19x19!(100), Dec19x19!(1000), Dec19x19!(1000), Dec19x19!(2000)];
+    /// let ys = [Dec19x19!(5), Dec19x19!(3), Dec19x19!(1), Dec19x19!(0.5), Dec19x19!(0.25)];
+    /// let f = |x: Dec19x19| Dec19x19::interpolate(&xs, &ys, x);
+    ///
+    /// // In range.
+    /// assert_eq!(f(Dec19x19!(50)), Some(Dec19x19!(4)));
+    /// assert_eq!(f(Dec19x19!(25)), Some(Dec19x19!(4.5)));
+    /// assert_eq!(f(Dec19x19!(550)), Some(Dec19x19!(2)));
+    /// assert_eq!(f(Dec19x19!(1500)), Some(Dec19x19!(0.375)));
+    ///
+    /// // Table points and boundaries.
+    /// assert_eq!(f(Dec19x19!(0)), Some(Dec19x19!(5)));
+    /// assert_eq!(f(Dec19x19!(100)), Some(Dec19x19!(3)));
+    /// assert_eq!(f(Dec19x19!(1000)), Some(Dec19x19!(1)));
+    /// assert_eq!(f(Dec19x19!(2000)), Some(Dec19x19!(0.25)));
+    ///
+    /// // Out of range.
+    /// assert_eq!(f(-Dec19x19::SMALLEST_STEP), None);
+    /// assert_eq!(f(Dec19x19!(2000) + Dec19x19::SMALLEST_STEP), None);
+    ///
+    /// // Invalid tables.
+    /// assert_eq!(Dec19x19::interpolate(&[], &[], Dec19x19!(0)), None);
+    /// assert_eq!(Dec19x19::interpolate(&xs, &ys[1..], Dec19x19!(50)), None);
+    ///
+    /// // Single point and full range tables.
+    /// let one = [Dec19x19!(1)];
+    /// assert_eq!(Dec19x19::interpolate(&one, &[Dec19x19!(7)], Dec19x19!(1)), Some(Dec19x19!(7)));
+    /// let xs = [Dec19x19::MIN, Dec19x19::MAX];
+    /// let ys = [Dec19x19::MAX, Dec19x19::MIN];
+    /// assert_eq!(Dec19x19::interpolate(&xs, &ys, Dec19x19::MIN), Some(Dec19x19::MAX));
+    /// assert_eq!(Dec19x19::interpolate(&xs, &ys, Dec19x19::MAX), Some(Dec19x19::MIN));
+    /// ```
     #[track_caller]
     #[inline(always)]
-    fn checked_pow(self, exp: i32) -> Option<Self::Output> {
-        let mut result = Dec19x19!(1);
-        let mut base   = if exp >= 0 { self } else { Dec19x19!(1) / self };
-        let mut e      = exp.unsigned_abs();
-        macro_rules! step {() => {
-            let e2 = e / 2;
-            let f2 = e % 2;
-            if f2 == 1 {
-                result = result.checked_mul(base)?;
-            }
-            e = e2;
-        };}
-        if e > 0 { step!(); }
-        while e > 0 {
-            base = base.checked_mul(base)?;
-            step!();
+    #[must_use]
+    pub fn interpolate(xs: &[Self], ys: &[Self], x: Self) -> Option<Self> {
+        if xs.len() != ys.len() || x < *xs.first()? || x > *xs.last()? {
+            return None;
         }
-        Some(result)
+        let i = xs.partition_point(|t| *t < x);
+        if xs[i] == x {
+            return Some(ys[i]);
+        }
+        let (x0, x1) = (xs[i - 1], xs[i]);
+        // Computed in `i256`, as `x1 - x0` can overflow. The result is in `0 ..= 1`.
+        let num = (i256_from_i128(x.repr) - i256_from_i128(x0.repr)) * FRAC_SCALE_I256;
+        let den = i256_from_i128(x1.repr) - i256_from_i128(x0.repr);
+        let t = Self::from_repr((num / den).as_i128());
+        Some(ys[i - 1].lerp(ys[i], t))
     }
 }
 
@@ -1725,6 +5727,7 @@ macro_rules! gen_from_x_for_fix128 {
             impl Dec19x19 {
                 #[track_caller]
                 #[inline(always)]
+                #[must_use]
                 pub const fn [<from_ $i>](value: $i) -> Self {
                     Self { repr: value as i128 * FRAC_SCALE_I128 }
                 }
@@ -1763,6 +5766,78 @@ impl TryFrom<i128> for Dec19x19 {
     }
 }
 
+impl Dec19x19 {
+    /// Converts an integer, returning `None` if it does not fit. Unlike `TryFrom<i128>`, this is
+    /// usable in `const` contexts, e.g. to build constant tables.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// const TABLE: [Option<Dec19x19>; 3] = [
+    ///     Dec19x19::from_i128_checked(1),
+    ///     Dec19x19::from_i128_checked(-1_000_000),
+    ///     Dec19x19::from_i128_checked(i128::MAX),
+    /// ];
+    /// assert_eq!(TABLE, [Some(Dec19x19!(1)), Some(Dec19x19!(-1_000_000)), None]);
+    /// let max_int = 17_014_118_346_046_923_173_i128;
+    /// check!( [Dec19x19::from_i128_checked] {
+    ///     (0) => Dec19x19!(0),
+    ///     (max_int) => Dec19x19::MAX_INT,
+    ///     (-max_int) => Dec19x19::MIN_INT,
+    ///     (max_int + 1) => FAIL,
+    ///     (i128::MIN) => FAIL,
+    /// });
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_i128_checked(value: i128) -> Option<Self> {
+        match value.checked_mul(FRAC_SCALE_I128) {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
+    }
+
+    /// Converts an integer, clamping values outside of the `MIN_INT..=MAX_INT` range instead of
+    /// failing on overflow. The range is symmetric, as `MIN_INT - 1` does not fit.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// let max_int = 17_014_118_346_046_923_173_i128;
+    /// check!( [Dec19x19::saturating_from_i128] {
+    ///     (0) => Dec19x19!(0),
+    ///     (-42) => Dec19x19!(-42),
+    ///     (max_int - 1) => Dec19x19::MAX_INT - Dec19x19!(1),
+    ///     (max_int) => Dec19x19::MAX_INT,
+    ///     (max_int + 1) => Dec19x19::MAX_INT,
+    ///     (i128::MAX) => Dec19x19::MAX_INT,
+    ///     (-max_int) => Dec19x19::MIN_INT,
+    ///     (-max_int - 1) => Dec19x19::MIN_INT,
+    ///     (-max_int - 2) => Dec19x19::MIN_INT,
+    ///     (i128::MIN) => Dec19x19::MIN_INT,
+    /// });
+    /// assert_eq!(Dec19x19::from_i128_checked(-max_int), Some(Dec19x19::MIN_INT));
+    /// assert_eq!(Dec19x19::from_i128_checked(-max_int - 1), None);
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    #[must_use]
+    pub const fn saturating_from_i128(value: i128) -> Self {
+        if value > Self::MAX_INT.repr / FRAC_SCALE_I128 {
+            Self::MAX_INT
+        } else if value < Self::MIN_INT.repr / FRAC_SCALE_I128 {
+            Self::MIN_INT
+        } else {
+            Self { repr: value * FRAC_SCALE_I128 }
+        }
+    }
+}
+
 impl TryFrom<u64> for Dec19x19 {
     type Error = &'static str;
     #[track_caller]
@@ -1803,82 +5878,578 @@ impl TryFrom<f32> for Dec19x19 {
     type Error = &'static str;
     #[track_caller]
     #[inline(always)]
-    fn try_from(value: f32) -> Result<Self, Self::Error>  {
-        (value as f64).try_into()
+    fn try_from(value: f32) -> Result<Self, Self::Error>  {
+        (value as f64).try_into()
+    }
+}
+
+impl Dec19x19 {
+    /// Converts the exact value represented by the `f64`, without rounding. Returns `None` if the
+    /// value needs more than 19 fractional digits (as `0.1_f64` does, which is actually
+    /// `0.1000000000000000055511151231257827…`), is out of range, or is not finite.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::from_f64_exact] {
+    ///     (0.5) => Dec19x19!(0.5),
+    ///     (-2.75) => Dec19x19!(-2.75),
+    ///     (0.0) => Dec19x19!(0),
+    ///     (-0.0) => Dec19x19!(0),
+    ///     (1e18) => Dec19x19!(1e18),
+    ///     (0.5_f64.powi(19)) => Dec19x19!(0.000_001_907_348_632_812_5),
+    ///     (-(2.0_f64.powi(63))) => Dec19x19!(-9_223_372_036_854_775_808),
+    ///     (0.1) => FAIL,
+    ///     (0.5_f64.powi(20)) => FAIL,
+    ///     (f64::MIN_POSITIVE) => FAIL,
+    ///     (2.0_f64.powi(64)) => FAIL,
+    ///     (1e300) => FAIL,
+    ///     (f64::NAN) => FAIL,
+    ///     (f64::INFINITY) => FAIL,
+    ///     (f64::NEG_INFINITY) => FAIL,
+    /// });
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub fn from_f64_exact(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        if value == 0.0 {
+            return Some(Dec19x19!(0));
+        }
+        // `value = mantissa * 2^exp`, with the mantissa stripped of trailing zeros.
+        let bits = value.to_bits();
+        let biased_exp = ((bits >> 52) & 0x7ff) as i32;
+        let frac_bits = bits & ((1 << 52) - 1);
+        let (mantissa, exp) = if biased_exp == 0 {
+            (frac_bits, -1074)
+        } else {
+            (frac_bits | (1 << 52), biased_exp - 1075)
+        };
+        let zeros = mantissa.trailing_zeros();
+        let mantissa = i128::from(mantissa >> zeros);
+        let exp = exp + zeros as i32;
+        // As `10^19 = 5^19 * 2^19`, the scaled value is an integer iff `exp >= -19`.
+        let repr = if exp >= 0 {
+            let pow2 = 1_i128.checked_shl(exp as u32).filter(|t| *t > 0)?;
+            mantissa.checked_mul(FRAC_SCALE_I128)?.checked_mul(pow2)?
+        } else if exp >= -(FRAC_PLACES as i32) {
+            let pow5 = 5_i128.pow(FRAC_PLACES);
+            mantissa.checked_mul(pow5 << (FRAC_PLACES as i32 + exp))?
+        } else {
+            return None;
+        };
+        Some(Self { repr: if value < 0.0 { -repr } else { repr } })
+    }
+
+    /// Creates the value `mantissa * 10^exp`, as sent by protocols that encode the mantissa and
+    /// the base-10 exponent separately. The scaling is done in integer space, without going
+    /// through a string. Returns `None` if the value is out of range or needs more than 19
+    /// fractional digits.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::try_from_scientific_parts] {
+    ///     (987, -19) => Dec19x19::SMALLEST_STEP * Dec19x19!(987),
+    ///     (15, -1) => Dec19x19!(1.5),
+    ///     (-15, -1) => Dec19x19!(-1.5),
+    ///     (15, 2) => Dec19x19!(1500),
+    ///     (1500, -3) => Dec19x19!(1.5),
+    ///     (150_000_000_000_000_000_000_000, -41) => Dec19x19!(0.000_000_000_000_000_001_5),
+    ///     (1, 19) => Dec19x19!(1e19),
+    ///     (0, 1000) => Dec19x19!(0),
+    ///     (0, -1000) => Dec19x19!(0),
+    ///     (i128::MAX, -19) => Dec19x19::MAX,
+    ///     (i128::MIN, -19) => Dec19x19::MIN,
+    ///     (1, -20) => FAIL,
+    ///     (1234, -21) => FAIL,
+    ///     (1, 20) => FAIL,
+    ///     (2, 19) => FAIL,
+    ///     (1, i32::MAX) => FAIL,
+    ///     (1, i32::MIN) => FAIL,
+    /// });
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn try_from_scientific_parts(mantissa: i128, exp: i32) -> Option<Self> {
+        use crate::i128_ops::POW10;
+        if mantissa == 0 {
+            return Some(Dec19x19!(0));
+        }
+        let shift = exp as i64 + FRAC_PLACES as i64;
+        let idx = shift.unsigned_abs();
+        if idx >= POW10.len() as u64 {
+            return None;
+        }
+        let scale = POW10[idx as usize];
+        if shift >= 0 {
+            match mantissa.checked_mul(scale) {
+                Some(repr) => Some(Self { repr }),
+                None => None,
+            }
+        } else if mantissa % scale != 0 {
+            None
+        } else {
+            Some(Self { repr: mantissa / scale })
+        }
+    }
+
+    /// Converts all values with `TryFrom<f64>`, stopping at the first failure, which is reported
+    /// along with its index.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(
+    ///     Dec19x19::try_from_f64_slice(&[1.5, -2.25, 0.0]),
+    ///     Ok(vec![Dec19x19!(1.5), Dec19x19!(-2.25), Dec19x19!(0)])
+    /// );
+    /// assert_eq!(Dec19x19::try_from_f64_slice(&[]), Ok(vec![]));
+    ///
+    /// let err = Dec19x19::try_from_f64_slice(&[1.5, 2.5, f64::NAN, 1e300]).unwrap_err();
+    /// assert_eq!(err.0, 2);
+    /// assert_eq!(err.1, "Cannot convert NaN or infinite value to Dec19x19.");
+    /// let err = Dec19x19::try_from_f64_slice(&[1.5, -1e30, f64::NAN]).unwrap_err();
+    /// assert_eq!(err, (1, "Underflow: Value too small to store in Dec19x19."));
+    /// assert_eq!(Dec19x19::try_from_f64_slice(&[f64::INFINITY]).unwrap_err().0, 0);
+    /// ```
+    #[track_caller]
+    pub fn try_from_f64_slice(xs: &[f64]) -> Result<Vec<Self>, (usize, &'static str)> {
+        xs.iter().enumerate().map(|(i, t)| Self::try_from(*t).map_err(|err| (i, err))).collect()
+    }
+}
+
+impl Dec19x19 {
+    /// Creates a value from the little-endian bytes of its `i128` representation.
+    #[must_use]
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Self { repr: i128::from_le_bytes(bytes) }
+    }
+
+    /// Returns the little-endian bytes of the `i128` representation.
+    #[must_use]
+    #[inline(always)]
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        self.repr.to_le_bytes()
+    }
+}
+
+/// Decodes the 16 little-endian bytes of the `i128` representation, the same as
+/// [`Dec19x19::from_le_bytes`]. Fails if the slice is not exactly 16 bytes long.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// let bytes = Dec19x19!(-3.25).to_le_bytes();
+/// assert_eq!(Dec19x19::try_from(&bytes[..]).ok(), Some(Dec19x19!(-3.25)));
+/// assert_eq!(Dec19x19::from_le_bytes(bytes), Dec19x19!(-3.25));
+/// assert_eq!(bytes, (-32_500_000_000_000_000_000_i128).to_le_bytes());
+/// assert_eq!(Dec19x19::try_from(&Dec19x19::MAX.to_le_bytes()[..]).ok(), Some(Dec19x19::MAX));
+/// assert_eq!(Dec19x19::try_from(&Dec19x19::MIN.to_le_bytes()[..]).ok(), Some(Dec19x19::MIN));
+/// assert!(Dec19x19::try_from(&[0_u8; 8][..]).is_err());
+/// assert!(Dec19x19::try_from(&[0_u8; 17][..]).is_err());
+/// assert!(Dec19x19::try_from(&[][..]).is_err());
+/// ```
+impl TryFrom<&[u8]> for Dec19x19 {
+    type Error = std::array::TryFromSliceError;
+    #[inline(always)]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self::from_le_bytes)
+    }
+}
+
+// =================================
+// === Conversions Dec19x19 -> X ===
+// =================================
+
+macro_rules! gen_from_fix128_for_x {
+    ($($i:ident),* $(,)?) => {
+        $(
+            impl From<Dec19x19> for $i {
+                #[track_caller]
+                #[inline(always)]
+                #[allow(trivial_numeric_casts)]
+                fn from(value: Dec19x19) -> Self {
+                    (value.repr / FRAC_SCALE_I128) as Self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! gen_fn_from_fix128_for_x {
+    ($($i:ident),* $(,)?) => { paste! {
+        $(
+            impl Dec19x19 {
+                #[track_caller]
+                #[inline(always)]
+                #[must_use]
+                pub fn [<into_ $i>](self) -> $i {
+                    self.into()
+                }
+            }
+        )*
+    }};
+}
+
+gen_from_fix128_for_x! { u64, i128, u128 }
+gen_fn_from_fix128_for_x! { u64, i128, u128, f64, f32 }
+
+macro_rules! gen_try_from_fix128_for_x {
+    ($($i:ident),* $(,)?) => {
+        $(
+            impl TryFrom<Dec19x19> for $i {
+                type Error = &'static str;
+                #[track_caller]
+                #[inline(always)]
+                fn try_from(value: Dec19x19) -> Result<Self, Self::Error> {
+                    let val = value.repr / FRAC_SCALE_I128;
+                    if val > $i::MAX as i128 || val < $i::MIN as i128 {
+                        return Err("Overflow: Dec19x19 too large or too small.");
+                    }
+                    Ok(val as $i)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! gen_fn_try_from_fix128_for_x {
+    ($($i:ident),* $(,)?) => { paste! {
+        $(
+            impl Dec19x19 {
+                #[track_caller]
+                #[inline(always)]
+                pub fn [<try_into_ $i>](self) -> Result<$i, <$i as TryFrom<Self>>::Error> {
+                    self.try_into()
+                }
+            }
+        )*
+    }};
+}
+
+gen_try_from_fix128_for_x! { i64, u32, i32, u16, i16, u8, i8 }
+gen_fn_try_from_fix128_for_x! { i64, u32, i32, u16, i16, u8, i8 }
+
+impl Dec19x19 {
+    /// Converts to any type implementing `TryFrom<Dec19x19>`, e.g. `x.cast::<i32>()`, reporting
+    /// failures as [`ConversionError::OutOfBounds`]. Like the underlying conversions, the integer
+    /// targets truncate the fractional part. Use the `try_into_*_exact` methods to reject it.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::ConversionError;
+    /// assert_eq!(Dec19x19!(42.9).cast::<i32>(), Ok(42));
+    /// assert_eq!(Dec19x19!(-42.9).cast::<i8>(), Ok(-42));
+    /// assert_eq!(Dec19x19!(255).cast::<u8>(), Ok(255));
+    /// assert_eq!(Dec19x19!(256).cast::<u8>(), Err(ConversionError::OutOfBounds));
+    /// assert_eq!(Dec19x19!(-1).cast::<u32>(), Err(ConversionError::OutOfBounds));
+    /// assert_eq!(Dec19x19::MAX.cast::<i64>(), Err(ConversionError::OutOfBounds));
+    /// assert_eq!(Dec19x19::MAX.cast::<i128>(), Ok(17_014_118_346_046_923_173));
+    /// assert_eq!(Dec19x19!(2.5).cast::<f64>(), Ok(2.5));
+    ///
+    /// fn cast_all<T: TryFrom<Dec19x19>>(values: &[Dec19x19]) -> Result<Vec<T>, ConversionError> {
+    ///     values.iter().map(|t| t.cast::<T>()).collect()
+    /// }
+    /// assert_eq!(cast_all::<u16>(&[Dec19x19!(1), Dec19x19!(2)]), Ok(vec![1, 2]));
+    /// let err = Err(ConversionError::OutOfBounds);
+    /// assert_eq!(cast_all::<u16>(&[Dec19x19!(1), Dec19x19!(-2)]), err);
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    pub fn cast<T>(self) -> Result<T, ConversionError>
+    where T: TryFrom<Self> {
+        T::try_from(self).map_err(|_| ConversionError::OutOfBounds)
+    }
+}
+
+// =============
+// === Parts ===
+// =============
+
+impl Dec19x19 {
+    /// Decomposes the value into its sign (`-1`, `0`, or `1`), the absolute integer part, and the
+    /// absolute fractional part. The fractional part is scaled by `10^19`, so it is always in the
+    /// range `0 .. 10^19`, e.g. `0.25` is represented as `2_500_000_000_000_000_000`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19!(3.25).to_parts(), (1, 3, 2_500_000_000_000_000_000));
+    /// assert_eq!(Dec19x19!(-3.25).to_parts(), (-1, 3, 2_500_000_000_000_000_000));
+    /// assert_eq!(Dec19x19!(-0.25).to_parts(), (-1, 0, 2_500_000_000_000_000_000));
+    /// assert_eq!(Dec19x19!(42).to_parts(), (1, 42, 0));
+    /// assert_eq!(Dec19x19!(0).to_parts(), (0, 0, 0));
+    /// assert_eq!(Dec19x19::SMALLEST_STEP.to_parts(), (1, 0, 1));
+    /// assert_eq!(
+    ///     Dec19x19::MAX.to_parts(),
+    ///     (1, 17_014_118_346_046_923_173, 1_687_303_715_884_105_727)
+    /// );
+    /// assert_eq!(
+    ///     Dec19x19::MIN.to_parts(),
+    ///     (-1, 17_014_118_346_046_923_173, 1_687_303_715_884_105_728)
+    /// );
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_parts(self) -> (i8, u128, u128) {
+        let abs = self.repr.unsigned_abs();
+        (self.sign(), abs / FRAC_SCALE_U128, abs % FRAC_SCALE_U128)
+    }
+
+    /// Reassembles a value from its parts, the inverse of [`Self::to_parts`]. Returns [`None`] if
+    /// `sign` is not `-1`, `0`, or `1`, if `sign` is `0` while the value is not zero, if `frac` is
+    /// not in the range `0 .. 10^19`, or if the value does not fit in [`Dec19x19`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19::from_parts(1, 3, 2_500_000_000_000_000_000), Some(Dec19x19!(3.25)));
+    /// assert_eq!(Dec19x19::from_parts(-1, 3, 2_500_000_000_000_000_000), Some(Dec19x19!(-3.25)));
+    /// assert_eq!(Dec19x19::from_parts(0, 0, 0), Some(Dec19x19!(0)));
+    /// assert_eq!(Dec19x19::from_parts(-1, 0, 0), Some(Dec19x19!(0)));
+    /// assert_eq!(
+    ///     Dec19x19::from_parts(-1, 17_014_118_346_046_923_173, 1_687_303_715_884_105_728),
+    ///     Some(Dec19x19::MIN)
+    /// );
+    ///
+    /// // Invalid parts.
+    /// assert_eq!(Dec19x19::from_parts(2, 3, 0), None);
+    /// assert_eq!(Dec19x19::from_parts(0, 3, 0), None);
+    /// assert_eq!(Dec19x19::from_parts(1, 3, 10_000_000_000_000_000_000), None);
+    /// let max_int = 17_014_118_346_046_923_173;
+    /// assert_eq!(Dec19x19::from_parts(1, max_int, 1_687_303_715_884_105_728), None);
+    /// assert_eq!(Dec19x19::from_parts(-1, max_int, 1_687_303_715_884_105_729), None);
+    /// assert_eq!(Dec19x19::from_parts(-1, max_int + 1, 0), None);
+    /// assert_eq!(Dec19x19::from_parts(1, u128::MAX, 0), None);
+    /// ```
+    ///
+    /// # Fuzzy
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// let round_trip = |t: Dec19x19| {
+    ///     let (sign, int, frac) = t.to_parts();
+    ///     assert_eq!(Dec19x19::from_parts(sign, int, frac), Some(t), "{t}");
+    /// };
+    /// round_trip(Dec19x19::MIN);
+    /// round_trip(Dec19x19::MAX);
+    /// fuzzy1::<Dec19x19, BigDecimal>(Series::new(0..=19, 0..=19), |f, _| round_trip(f));
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_parts(sign: i8, int: u128, frac: u128) -> Option<Self> {
+        if frac >= FRAC_SCALE_U128 {
+            return None;
+        }
+        let Some(scaled) = int.checked_mul(FRAC_SCALE_U128) else { return None };
+        let Some(mag) = scaled.checked_add(frac) else { return None };
+        match sign {
+            0 if mag == 0 => Some(Self { repr: 0 }),
+            1 if mag <= i128::MAX as u128 => Some(Self { repr: mag as i128 }),
+            -1 if mag <= i128::MIN.unsigned_abs() =>
+                Some(Self { repr: (mag as i128).wrapping_neg() }),
+            _ => None,
+        }
+    }
+}
+
+// =======================
+// === Scaled Integers ===
+// =======================
+
+impl Dec19x19 {
+    /// Converts the value to an integer at a different fixed scale, i.e. returns
+    /// `self * 10^frac_digits`, e.g. to convert an amount to minor units. If `frac_digits` is
+    /// below 19, the digits that do not fit are rounded to the nearest value, away from zero on
+    /// tie, the same as [`RoundTo::round_to`]. Returns `None` if the result does not fit in
+    /// [`i128`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::to_scaled_i128] {
+    ///     (Dec19x19!(1.234_567_89), 8) => 123_456_789,
+    ///     (Dec19x19!(-1.234_567_89), 8) => -123_456_789,
+    ///     (Dec19x19!(1.234_567_894), 8) => 123_456_789,
+    ///     (Dec19x19!(1.234_567_895), 8) => 123_456_790,
+    ///     (Dec19x19!(-1.234_567_895), 8) => -123_456_790,
+    ///     (Dec19x19!(12.34), 2) => 1234,
+    ///     (Dec19x19!(12.5), 0) => 13,
+    ///     (Dec19x19!(0), 30) => 0,
+    ///     (Dec19x19::SMALLEST_STEP, 19) => 1,
+    ///     (Dec19x19::SMALLEST_STEP, 25) => 1_000_000,
+    ///     (Dec19x19::MAX, 19) => i128::MAX,
+    ///     (Dec19x19::MIN, 19) => i128::MIN,
+    ///     (Dec19x19::MAX, 0) => 17_014_118_346_046_923_173,
+    ///     (Dec19x19!(1), 38) => 100_000_000_000_000_000_000_000_000_000_000_000_000,
+    ///     (Dec19x19::MAX, 20) => FAIL,
+    ///     (Dec19x19!(2), 38) => FAIL,
+    ///     (Dec19x19::SMALLEST_STEP, 60) => FAIL,
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_scaled_i128(self, frac_digits: u32) -> Option<i128> {
+        use crate::i128_ops::POW10;
+        if frac_digits <= FRAC_PLACES {
+            let scale = POW10[(FRAC_PLACES - frac_digits) as usize];
+            let quot = self.repr / scale;
+            let rem = self.repr % scale;
+            if rem.unsigned_abs() * 2 >= scale as u128 {
+                Some(quot + rem.signum())
+            } else {
+                Some(quot)
+            }
+        } else if self.repr == 0 {
+            Some(0)
+        } else if (frac_digits - FRAC_PLACES) as usize >= POW10.len() {
+            None
+        } else {
+            self.repr.checked_mul(POW10[(frac_digits - FRAC_PLACES) as usize])
+        }
+    }
+
+    /// Creates the value `value * 10^-frac_digits` from an integer at a different fixed scale,
+    /// e.g. an amount in satoshis at scale 8. The inverse of [`Self::to_scaled_i128`]. Returns
+    /// `None` if `frac_digits` is above 19 or the value does not fit.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use validator::*;
+    /// check! ( [Dec19x19::from_scaled_i128] {
+    ///     (123_456_789, 8) => Dec19x19!(1.234_567_89),
+    ///     (-123_456_789, 8) => Dec19x19!(-1.234_567_89),
+    ///     (2_100_000_000_000_000, 8) => Dec19x19!(21_000_000),
+    ///     (1234, 2) => Dec19x19!(12.34),
+    ///     (42, 0) => Dec19x19!(42),
+    ///     (1, 19) => Dec19x19::SMALLEST_STEP,
+    ///     (i128::MAX, 19) => Dec19x19::MAX,
+    ///     (i128::MIN, 19) => Dec19x19::MIN,
+    ///     (17_014_118_346_046_923_173, 0) => Dec19x19::MAX_INT,
+    ///     (17_014_118_346_046_923_174, 0) => FAIL,
+    ///     (i128::MAX, 8) => FAIL,
+    ///     (1, 20) => FAIL,
+    /// });
+    ///
+    /// for sats in [0, 1, -1, 123_456_789, 2_100_000_000_000_000, i64::MAX as i128] {
+    ///     let value = Dec19x19::from_scaled_i128(sats, 8).unwrap();
+    ///     assert_eq!(value.to_scaled_i128(8), Some(sats));
+    /// }
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_scaled_i128(value: i128, frac_digits: u32) -> Option<Self> {
+        if frac_digits > FRAC_PLACES {
+            return None;
+        }
+        let scale = crate::i128_ops::POW10[(FRAC_PLACES - frac_digits) as usize];
+        match value.checked_mul(scale) {
+            Some(repr) => Some(Self { repr }),
+            None => None,
+        }
     }
 }
 
-// =================================
-// === Conversions Dec19x19 -> X ===
-// =================================
+// =======================
+// === ConversionError ===
+// =======================
 
-macro_rules! gen_from_fix128_for_x {
-    ($($i:ident),* $(,)?) => {
-        $(
-            impl From<Dec19x19> for $i {
-                #[track_caller]
-                #[inline(always)]
-                #[allow(trivial_numeric_casts)]
-                fn from(value: Dec19x19) -> Self {
-                    (value.repr / FRAC_SCALE_I128) as Self
-                }
-            }
-        )*
-    };
+/// Error returned by the strict `Dec19x19 -> X` conversions.
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use fixed_num::dec19x19::ConversionError;
+/// assert_eq!(Dec19x19!(3).try_into_i128_exact(), Ok(3));
+/// assert_eq!(Dec19x19!(-3).try_into_i8_exact(), Ok(-3));
+/// assert_eq!(Dec19x19!(3.5).try_into_i128_exact(), Err(ConversionError::Fractional));
+/// assert_eq!(Dec19x19!(3.5).try_into_u8_exact(), Err(ConversionError::Fractional));
+/// assert_eq!(Dec19x19::SMALLEST_STEP.try_into_i64_exact(), Err(ConversionError::Fractional));
+/// assert_eq!(Dec19x19!(256).try_into_u8_exact(), Err(ConversionError::OutOfBounds));
+/// assert_eq!(Dec19x19!(-1).try_into_u128_exact(), Err(ConversionError::OutOfBounds));
+/// assert_eq!(Dec19x19::MAX_INT.try_into_i128_exact(), Ok(17_014_118_346_046_923_173));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConversionError {
+    /// The value has a non-zero fractional part.
+    Fractional,
+    /// The value does not fit in the target type.
+    OutOfBounds,
 }
 
-macro_rules! gen_fn_from_fix128_for_x {
-    ($($i:ident),* $(,)?) => { paste! {
-        $(
-            impl Dec19x19 {
-                #[track_caller]
-                #[inline(always)]
-                pub fn [<into_ $i>](self) -> $i {
-                    self.into()
-                }
-            }
-        )*
-    }};
+impl std::error::Error for ConversionError {}
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fractional => write!(f, "Value has a fractional part"),
+            Self::OutOfBounds => write!(f, "Value out of bounds"),
+        }
+    }
 }
 
-gen_from_fix128_for_x! { u64, i128, u128 }
-gen_fn_from_fix128_for_x! { u64, i128, u128, f64, f32 }
-
-macro_rules! gen_try_from_fix128_for_x {
-    ($($i:ident),* $(,)?) => {
+macro_rules! gen_fn_try_into_x_exact {
+    ($($i:ident),* $(,)?) => { paste! {
         $(
-            impl TryFrom<Dec19x19> for $i {
-                type Error = &'static str;
+            impl Dec19x19 {
+                /// Converts to an integer, failing if the value has a fractional part or does not
+                /// fit in the target type.
                 #[track_caller]
                 #[inline(always)]
-                fn try_from(value: Dec19x19) -> Result<Self, Self::Error> {
-                    let val = value.repr / FRAC_SCALE_I128;
+                #[allow(trivial_numeric_casts)]
+                pub fn [<try_into_ $i _exact>](self) -> Result<$i, ConversionError> {
+                    if self.repr % FRAC_SCALE_I128 != 0 {
+                        return Err(ConversionError::Fractional);
+                    }
+                    let val = self.repr / FRAC_SCALE_I128;
                     if val > $i::MAX as i128 || val < $i::MIN as i128 {
-                        return Err("Overflow: Dec19x19 too large or too small.");
+                        return Err(ConversionError::OutOfBounds);
                     }
                     Ok(val as $i)
                 }
             }
         )*
-    };
-}
-
-macro_rules! gen_fn_try_from_fix128_for_x {
-    ($($i:ident),* $(,)?) => { paste! {
-        $(
-            impl Dec19x19 {
-                #[track_caller]
-                #[inline(always)]
-                pub fn [<try_into_ $i>](self) -> Result<$i, <$i as TryFrom<Self>>::Error> {
-                    self.try_into()
-                }
-            }
-        )*
     }};
 }
 
-gen_try_from_fix128_for_x! { i64, u32, i32, u16, i16, u8, i8 }
-gen_fn_try_from_fix128_for_x! { i64, u32, i32, u16, i16, u8, i8 }
+gen_fn_try_into_x_exact! { i128, i64, u32, i32, u16, i16, u8, i8 }
+
+impl Dec19x19 {
+    /// Converts to an `u64`, failing if the value has a fractional part or is out of bounds.
+    #[track_caller]
+    #[inline(always)]
+    pub fn try_into_u64_exact(self) -> Result<u64, ConversionError> {
+        let val = self.try_into_i128_exact()?;
+        u64::try_from(val).map_err(|_| ConversionError::OutOfBounds)
+    }
+
+    /// Converts to an `u128`, failing if the value has a fractional part or is negative.
+    #[track_caller]
+    #[inline(always)]
+    pub fn try_into_u128_exact(self) -> Result<u128, ConversionError> {
+        let val = self.try_into_i128_exact()?;
+        u128::try_from(val).map_err(|_| ConversionError::OutOfBounds)
+    }
+}
 
 impl From<Dec19x19> for f64 {
     #[track_caller]
@@ -1890,6 +6461,41 @@ impl From<Dec19x19> for f64 {
     }
 }
 
+impl Dec19x19 {
+    /// Converts to `f64` with a single division of the representation, which is faster than
+    /// `f64::from`, but rounds twice: once when converting the `i128` representation to `f64`
+    /// and once when dividing by `10^19`. The result therefore has a relative error of at most
+    /// `2^-52` (one ULP), and may differ from `f64::from` in the last bit. Prefer `f64::from` when
+    /// the result is used for further computations, and this method for hot paths like
+    /// rendering.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19!(0).to_f64_fast(), 0.0);
+    /// assert_eq!(Dec19x19!(1.5).to_f64_fast(), 1.5);
+    /// assert_eq!(Dec19x19!(-2.25).to_f64_fast(), -2.25);
+    /// assert_eq!(Dec19x19!(1e18).to_f64_fast(), 1e18);
+    /// for value in [
+    ///     Dec19x19!(0.1),
+    ///     Dec19x19!(-1234.567_890_123_456_789_1),
+    ///     Dec19x19::SMALLEST_STEP,
+    ///     Dec19x19::MAX,
+    ///     Dec19x19::MIN,
+    /// ] {
+    ///     let exact = f64::from(value);
+    ///     let fast = value.to_f64_fast();
+    ///     assert!((fast - exact).abs() <= exact.abs() * f64::EPSILON);
+    /// }
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_f64_fast(self) -> f64 {
+        self.repr as f64 / FRAC_SCALE_F64
+    }
+}
+
 impl From<Dec19x19> for f32 {
     #[track_caller]
     #[inline(always)]
@@ -1925,6 +6531,60 @@ impl From<Dec19x19> for f32 {
 /// assert!(Dec19x19::from_str("-17_014_118_346_046_923_173.168_730_371_588_410_572_9").is_err());
 /// assert!(Dec19x19::from_str("987e+17").is_err());
 /// assert!(Dec19x19::from_str("987e-20").is_err());
+///
+/// // Omitted integer or fractional part.
+/// assert_eq!(Dec19x19::from_str("5."), Ok(Dec19x19!(5)));
+/// assert_eq!(Dec19x19::from_str(".5"), Ok(Dec19x19!(0.5)));
+/// assert_eq!(Dec19x19::from_str("-5."), Ok(Dec19x19!(-5)));
+/// assert_eq!(Dec19x19::from_str("-.5"), Ok(Dec19x19!(-0.5)));
+/// assert_eq!(Dec19x19::from_str(".5e1"), Ok(Dec19x19!(5)));
+/// assert!(Dec19x19::from_str(".").is_err());
+/// assert!(Dec19x19::from_str("-.").is_err());
+/// assert!(Dec19x19::from_str("-").is_err());
+///
+/// // No digits at all.
+/// use fixed_num_helper::ParseDec19x19Error;
+/// let empty = "".parse::<i128>().unwrap_err();
+/// assert_eq!(Dec19x19::from_str(""), Err(ParseDec19x19Error::ParseIntError(empty)));
+/// assert!(Dec19x19::from_str("   ").is_err());
+/// assert_eq!(
+///     Dec19x19::from_str("e5"),
+///     Err(ParseDec19x19Error::InvalidChar { char: 'e', pos: 0 })
+/// );
+/// assert_eq!(
+///     Dec19x19::from_str("-e5"),
+///     Err(ParseDec19x19Error::InvalidChar { char: 'e', pos: 1 })
+/// );
+/// assert!(Dec19x19::from_str(".e5").is_err());
+///
+/// // Sign is not moved by the exponent.
+/// assert_eq!(Dec19x19::from_str("-5e-1"), Ok(Dec19x19!(-0.5)));
+/// assert_eq!(Dec19x19::from_str("-5e-2"), Ok(Dec19x19!(-0.05)));
+/// assert!(Dec19x19::from_str("--5").is_err());
+/// ```
+///
+/// # Round-trip
+///
+/// ```
+/// # use fixed_num::*;
+/// # use validator::*;
+/// use std::str::FromStr;
+/// let round_trip = |t: Dec19x19| {
+///     assert_eq!(Dec19x19::from_str(&t.to_string()), Ok(t), "{t}");
+///     assert_eq!(Dec19x19::from_str(&format!("{t:#}")), Ok(t), "{t:#}");
+/// };
+/// round_trip(Dec19x19::MIN);
+/// round_trip(Dec19x19::MAX);
+/// round_trip(Dec19x19::SMALLEST_STEP);
+/// round_trip(-Dec19x19::SMALLEST_STEP);
+/// round_trip(Dec19x19::MIN_INT);
+/// round_trip(Dec19x19::MAX_INT);
+/// round_trip(Dec19x19!(0));
+/// round_trip(Dec19x19!(-0.5));
+/// round_trip(Dec19x19!(0.100_000_000_000_000_000_0));
+/// round_trip(Dec19x19!(1.000_000_000_000_000_001_0));
+/// round_trip(Dec19x19!(-1.000_000_000_000_000_000_1));
+/// fuzzy1::<Dec19x19, BigDecimal>(Series::new(0..=19, 0..=19), |f, _| round_trip(f));
 /// ```
 impl FromStr for Dec19x19 {
     type Err = ParseDec19x19Error;
@@ -1934,6 +6594,258 @@ impl FromStr for Dec19x19 {
     }
 }
 
+impl Dec19x19 {
+    /// Parses all strings in the slice. On failure, returns the index of the first string that
+    /// could not be parsed alongside the error.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num_helper::ParseDec19x19Error;
+    /// assert_eq!(
+    ///     Dec19x19::parse_all(&["1", "-2.5", "1e-3"]),
+    ///     Ok(vec![Dec19x19!(1), Dec19x19!(-2.5), Dec19x19!(0.001)])
+    /// );
+    /// assert_eq!(Dec19x19::parse_all(&[]), Ok(vec![]));
+    /// assert_eq!(
+    ///     Dec19x19::parse_all(&["1", "2", "1e-20", "x"]),
+    ///     Err((2, ParseDec19x19Error::TooPrecise))
+    /// );
+    /// assert!(matches!(Dec19x19::parse_all(&["1", "x"]), Err((1, _))));
+    /// ```
+    pub fn parse_all(strs: &[&str]) -> Result<Vec<Self>, (usize, ParseDec19x19Error)> {
+        strs.iter()
+            .enumerate()
+            .map(|(i, s)| Self::from_str(s).map_err(|err| (i, err)))
+            .collect()
+    }
+
+    /// Parses the raw `repr` in the given radix, e.g. from a hex or binary debug dump. The input is
+    /// interpreted as the internal `i128` representation, not as a decimal value, so `"a"` parsed
+    /// with radix `16` is `10 * SMALLEST_STEP`. Negative values are expected to be prefixed with
+    /// `-`, as in [`i128::from_str_radix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2 ..= 36`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19::from_repr_str_radix("a", 16), Ok(Dec19x19::from_repr(10)));
+    /// assert_eq!(Dec19x19::from_repr_str_radix("-101", 2), Ok(Dec19x19::from_repr(-5)));
+    /// assert!(Dec19x19::from_repr_str_radix("12", 2).is_err());
+    /// assert!(Dec19x19::from_repr_str_radix("1.5", 10).is_err());
+    ///
+    /// let hex = |t: Dec19x19| {
+    ///     let sign = if t.repr < 0 { "-" } else { "" };
+    ///     format!("{sign}{:x}", t.repr.unsigned_abs())
+    /// };
+    /// let bin = |t: Dec19x19| {
+    ///     let sign = if t.repr < 0 { "-" } else { "" };
+    ///     format!("{sign}{:b}", t.repr.unsigned_abs())
+    /// };
+    /// let values = [
+    ///     Dec19x19!(0), Dec19x19!(1), Dec19x19!(-3.25), Dec19x19::SMALLEST_STEP,
+    ///     Dec19x19::MAX, Dec19x19::MIN,
+    /// ];
+    /// for t in values {
+    ///     assert_eq!(Dec19x19::from_repr_str_radix(&hex(t), 16), Ok(t));
+    ///     assert_eq!(Dec19x19::from_repr_str_radix(&bin(t), 2), Ok(t));
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn from_repr_str_radix(s: &str, radix: u32) -> Result<Self, std::num::ParseIntError> {
+        i128::from_str_radix(s, radix).map(Self::from_repr)
+    }
+}
+
+/// Error returned by [`Dec19x19::from_ratio_str`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseRatioError {
+    /// The numerator or the denominator is not a valid number.
+    Parse(ParseDec19x19Error),
+    /// The input does not contain exactly one `/`.
+    InvalidFormat,
+    /// The denominator is zero.
+    ZeroDenominator,
+    /// The quotient does not fit in `Dec19x19`.
+    OutOfBounds,
+}
+
+impl From<ParseDec19x19Error> for ParseRatioError {
+    fn from(err: ParseDec19x19Error) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl std::error::Error for ParseRatioError {}
+impl std::fmt::Display for ParseRatioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => std::fmt::Display::fmt(err, f),
+            Self::InvalidFormat => write!(f, "Expected a ratio in the form `a/b`"),
+            Self::ZeroDenominator => write!(f, "Denominator is zero"),
+            Self::OutOfBounds => write!(f, "Value out of bounds"),
+        }
+    }
+}
+
+impl Dec19x19 {
+    /// Parses a ratio in the form `a/b`, e.g. `"22/7"`, where both sides are any values accepted
+    /// by [`FromStr`], optionally surrounded by whitespace. The quotient is computed in `i256`
+    /// and truncated to 19 fractional digits, the same as [`Div`].
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::ParseRatioError;
+    /// # use fixed_num_helper::ParseDec19x19Error;
+    /// assert_eq!(Dec19x19::from_ratio_str("1/3"), Ok(Dec19x19!(0.333_333_333_333_333_333_3)));
+    /// assert_eq!(Dec19x19::from_ratio_str("22/7"), Ok(Dec19x19!(3.142_857_142_857_142_857_1)));
+    /// assert_eq!(Dec19x19::from_ratio_str("-3/4"), Ok(Dec19x19!(-0.75)));
+    /// assert_eq!(Dec19x19::from_ratio_str(" 1.5 / 0.5 "), Ok(Dec19x19!(3)));
+    /// assert_eq!(Dec19x19::from_ratio_str("1/0"), Err(ParseRatioError::ZeroDenominator));
+    /// assert_eq!(Dec19x19::from_ratio_str("1/0.0"), Err(ParseRatioError::ZeroDenominator));
+    /// assert_eq!(Dec19x19::from_ratio_str("1/2/3"), Err(ParseRatioError::InvalidFormat));
+    /// assert_eq!(Dec19x19::from_ratio_str("12"), Err(ParseRatioError::InvalidFormat));
+    /// assert!(matches!(Dec19x19::from_ratio_str("1/x"), Err(ParseRatioError::Parse(_))));
+    /// assert_eq!(
+    ///     Dec19x19::from_ratio_str("1/1e-20"),
+    ///     Err(ParseRatioError::Parse(ParseDec19x19Error::TooPrecise))
+    /// );
+    /// assert_eq!(
+    ///     Dec19x19::from_ratio_str("1e18/0.01"),
+    ///     Err(ParseRatioError::OutOfBounds)
+    /// );
+    /// ```
+    pub fn from_ratio_str(s: &str) -> Result<Self, ParseRatioError> {
+        let mut parts = s.split('/');
+        let (Some(num), Some(den), None) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(ParseRatioError::InvalidFormat);
+        };
+        let num = Self::from_str(num.trim())?;
+        let den = Self::from_str(den.trim())?;
+        if den.is_zero() {
+            return Err(ParseRatioError::ZeroDenominator);
+        }
+        num.checked_div(den).ok_or(ParseRatioError::OutOfBounds)
+    }
+}
+
+/// Error returned by [`Dec19x19::from_str_in_range`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum RangeParseError {
+    /// The input is not a valid number.
+    Parse(ParseDec19x19Error),
+    /// The value is smaller than the given minimum.
+    BelowMin(Dec19x19),
+    /// The value is bigger than the given maximum.
+    AboveMax(Dec19x19),
+}
+
+impl From<ParseDec19x19Error> for RangeParseError {
+    fn from(err: ParseDec19x19Error) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl std::error::Error for RangeParseError {}
+impl std::fmt::Display for RangeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => std::fmt::Display::fmt(err, f),
+            Self::BelowMin(min) => write!(f, "Value must be at least {min}"),
+            Self::AboveMax(max) => write!(f, "Value must be at most {max}"),
+        }
+    }
+}
+
+impl Dec19x19 {
+    /// Parses the value with [`FromStr`] and checks that it lies in the `lo ..= hi` range,
+    /// reporting parse failures and each side of the range with a distinct error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi` and debug assertions are enabled.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num::dec19x19::RangeParseError;
+    /// # use fixed_num_helper::ParseDec19x19Error;
+    /// let parse = |s: &str| Dec19x19::from_str_in_range(s, Dec19x19!(0), Dec19x19!(100));
+    /// assert_eq!(parse("42.5"), Ok(Dec19x19!(42.5)));
+    /// assert_eq!(parse("0"), Ok(Dec19x19!(0)));
+    /// assert_eq!(parse("100"), Ok(Dec19x19!(100)));
+    /// assert_eq!(parse("-0.01"), Err(RangeParseError::BelowMin(Dec19x19!(0))));
+    /// let above = Err(RangeParseError::AboveMax(Dec19x19!(100)));
+    /// assert_eq!(parse("100.0000000000000000001"), above);
+    /// assert_eq!(parse("1e30"), Err(RangeParseError::Parse(ParseDec19x19Error::OutOfBounds)));
+    /// assert!(matches!(parse("abc"), Err(RangeParseError::Parse(_))));
+    /// assert!(matches!(parse("1.2.3"), Err(RangeParseError::Parse(_))));
+    /// assert_eq!(parse("-1").unwrap_err().to_string(), "Value must be at least 0");
+    /// assert_eq!(parse("101").unwrap_err().to_string(), "Value must be at most 100");
+    /// ```
+    #[track_caller]
+    pub fn from_str_in_range(s: &str, lo: Self, hi: Self) -> Result<Self, RangeParseError> {
+        debug_assert!(lo <= hi, "Empty range: {lo} > {hi}");
+        let value = Self::from_str(s)?;
+        if value < lo {
+            Err(RangeParseError::BelowMin(lo))
+        } else if value > hi {
+            Err(RangeParseError::AboveMax(hi))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl Dec19x19 {
+    /// Parses a value sent as separate integer and fractional digit fields, e.g. `("123", "456")`
+    /// for `123.456`. The sign is taken from the integer part, so `("-0", "5")` is `-0.5`. The
+    /// fractional part has to consist of ASCII digits only and can not be more precise than 19
+    /// digits (trailing zeros are allowed).
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num_helper::ParseDec19x19Error;
+    /// let parse = Dec19x19::from_int_and_frac_str;
+    /// assert_eq!(parse("123", "456"), Ok(Dec19x19!(123.456)));
+    /// assert_eq!(parse("123", ""), Ok(Dec19x19!(123)));
+    /// assert_eq!(parse("", "5"), Ok(Dec19x19!(0.5)));
+    /// assert_eq!(parse("-12", "05"), Ok(Dec19x19!(-12.05)));
+    /// assert_eq!(parse("-0", "5"), Ok(Dec19x19!(-0.5)));
+    /// assert_eq!(parse("1", "0000000000000000001"), Ok(Dec19x19!(1) + Dec19x19::SMALLEST_STEP));
+    /// assert_eq!(parse("1", "50000000000000000000"), Ok(Dec19x19!(1.5)));
+    /// assert_eq!(parse("1", "00000000000000000001"), Err(ParseDec19x19Error::TooPrecise));
+    /// assert_eq!(parse("1", "5e3"), Err(ParseDec19x19Error::InvalidChar { char: 'e', pos: 1 }));
+    /// assert_eq!(parse("1", "-5"), Err(ParseDec19x19Error::InvalidChar { char: '-', pos: 0 }));
+    /// assert!(parse("1.5", "5").is_err());
+    /// assert!(parse("1e2", "5").is_err());
+    /// assert_eq!(parse("17014118346046923173", "1687303715884105727"), Ok(Dec19x19::MAX));
+    /// assert_eq!(
+    ///     parse("17014118346046923173", "1687303715884105728"),
+    ///     Err(ParseDec19x19Error::OutOfBounds)
+    /// );
+    /// ```
+    pub fn from_int_and_frac_str(int: &str, frac: &str) -> Result<Self, ParseDec19x19Error> {
+        if let Some((pos, char)) = frac.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseDec19x19Error::InvalidChar { char, pos });
+        }
+        if frac.trim_end_matches('0').len() > FRAC_PLACES as usize {
+            return Err(ParseDec19x19Error::TooPrecise);
+        }
+        Self::from_str(&format!("{int}.{frac}"))
+    }
+}
+
 impl<'t> TryFrom<&'t str> for Dec19x19 {
     type Error = ParseDec19x19Error;
     fn try_from(s: &'t str) -> Result<Self, Self::Error> {
@@ -1965,11 +6877,203 @@ impl std::fmt::Display for Dec19x19 {
             align: f.align(),
             fill: f.fill(),
             sign_plus: f.sign_plus(),
+            ..Formatter::default()
         };
         write!(f, "{}", self.format(&mut formatter))
     }
 }
 
+impl Dec19x19 {
+    /// Returns the canonical textual form of the value: no digit separators, no sign for
+    /// non-negative values, an explicit leading `0` for values below one, and the minimal number
+    /// of fractional digits. Unlike [`Display`](std::fmt::Display), the output does not depend on
+    /// any formatter flags, so equal values always produce identical strings, which makes it
+    /// suitable for hashing and deduplication.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19!(-0.5).to_canonical_string(), "-0.5");
+    /// assert_eq!(Dec19x19!(1000).to_canonical_string(), "1000");
+    /// assert_eq!(Dec19x19!(1_000.000).to_canonical_string(), "1000");
+    /// assert_eq!(Dec19x19!(0).to_canonical_string(), "0");
+    /// assert_eq!(Dec19x19!(-0.0).to_canonical_string(), "0");
+    /// assert_eq!(Dec19x19!(0.5e1).to_canonical_string(), Dec19x19!(5).to_canonical_string());
+    /// assert_eq!(
+    ///     Dec19x19::MIN.to_canonical_string(),
+    ///     "-17014118346046923173.1687303715884105728"
+    /// );
+    /// assert!(!Dec19x19!(1_234_567.123_456).to_canonical_string().contains('_'));
+    /// ```
+    #[must_use]
+    pub fn to_canonical_string(self) -> String {
+        self.format(&mut Formatter::default())
+    }
+
+    /// Formats the value using the provided options. See [`DisplayOptions`] to learn more.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// # use fixed_num_helper::DisplayOptions;
+    /// let opts = DisplayOptions::new();
+    /// assert_eq!(Dec19x19!(1234.5).display(&opts), "1234.5");
+    ///
+    /// let opts = DisplayOptions::new().separator('_').precision(3);
+    /// assert_eq!(Dec19x19!(1234567.5).display(&opts), "1_234_567.500");
+    /// assert_eq!(Dec19x19!(-1234.56789).display(&opts), "-1_234.568");
+    ///
+    /// let opts = DisplayOptions::new().separator(',').precision(2).sign_plus(true);
+    /// assert_eq!(Dec19x19!(1234567).display(&opts), "+1,234,567.00");
+    /// assert_eq!(Dec19x19!(-1234567).display(&opts), "-1,234,567.00");
+    ///
+    /// let opts = DisplayOptions::new().precision(1).width(8).fill('*').sign_plus(true);
+    /// assert_eq!(Dec19x19!(3.14).display(&opts), "****+3.1");
+    ///
+    /// let opts = opts.align(std::fmt::Alignment::Center);
+    /// assert_eq!(Dec19x19!(3.14).display(&opts), "**+3.1**");
+    ///
+    /// let opts = DisplayOptions::new().separator(',').min_group_digits(4);
+    /// assert_eq!(Dec19x19!(1000).display(&opts), "1000");
+    /// assert_eq!(Dec19x19!(-9999.5).display(&opts), "-9999.5");
+    /// assert_eq!(Dec19x19!(10000).display(&opts), "10,000");
+    /// assert_eq!(Dec19x19!(1234567.5).display(&opts), "1,234,567.5");
+    /// assert_eq!(Dec19x19!(1234.123456).display(&opts), "1234.123,456");
+    /// assert_eq!(Dec19x19!(1000).display(&DisplayOptions::new().separator(',')), "1,000");
+    /// ```
+    #[must_use]
+    pub fn display(self, opts: &DisplayOptions) -> String {
+        self.format(&mut opts.formatter())
+    }
+
+    /// Formats the value for tabular or currency output: rounded to exactly `frac_digits`
+    /// fractional digits, with trailing zeros kept, and the integer part grouped by `separator`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19!(1000).to_money_string(2, Some(',')), "1,000.00");
+    /// assert_eq!(Dec19x19!(1.5).to_money_string(2, Some(',')), "1.50");
+    /// assert_eq!(Dec19x19!(-1234567.891).to_money_string(2, Some(',')), "-1,234,567.89");
+    /// assert_eq!(Dec19x19!(2.005).to_money_string(2, None), "2.01");
+    /// assert_eq!(Dec19x19!(1000).to_money_string(2, None), "1000.00");
+    /// assert_eq!(Dec19x19!(1234.5).to_money_string(0, Some('_')), "1_235");
+    /// assert_eq!(Dec19x19!(0).to_money_string(3, Some(',')), "0.000");
+    /// ```
+    #[must_use]
+    pub fn to_money_string(self, frac_digits: usize, separator: Option<char>) -> String {
+        let precision = Some(frac_digits);
+        let mut formatter = Formatter { separator, precision, ..Formatter::default() };
+        self.format(&mut formatter)
+    }
+}
+
+/// Place of a digit yielded by [`Dec19x19::digits_iter`]. The change from [`Self::Int`] to
+/// [`Self::Frac`] marks the decimal point.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum DigitPosition {
+    /// Digit of the integer part with the place value of `10^n`, `Int(0)` being the units.
+    Int(u32),
+    /// Digit of the fractional part with the place value of `10^-n`, `Frac(1)` being the tenths.
+    Frac(u32),
+}
+
+impl Dec19x19 {
+    /// Iterates over the decimal digits of the absolute value, from the most significant one,
+    /// along with their places. The digits are the same as displayed: the integer part has no
+    /// leading zeros, but at least one digit, and the fractional part has no trailing zeros. The
+    /// sign is not yielded, use [`Self::sign`] to get it.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// use fixed_num::dec19x19::DigitPosition::*;
+    /// let digits = |t: Dec19x19| t.digits_iter().collect::<Vec<_>>();
+    /// assert_eq!(digits(Dec19x19!(123.45)), [
+    ///     (Int(2), 1), (Int(1), 2), (Int(0), 3), (Frac(1), 4), (Frac(2), 5)
+    /// ]);
+    /// assert_eq!(digits(Dec19x19!(-0.05)), [(Int(0), 0), (Frac(1), 0), (Frac(2), 5)]);
+    /// assert_eq!(digits(Dec19x19!(-120)), [(Int(2), 1), (Int(1), 2), (Int(0), 0)]);
+    /// assert_eq!(digits(Dec19x19!(0)), [(Int(0), 0)]);
+    /// assert_eq!(digits(Dec19x19::SMALLEST_STEP).last(), Some(&(Frac(19), 1)));
+    /// for t in [Dec19x19::MAX, Dec19x19::MIN, Dec19x19!(-987.654), Dec19x19!(1e-5)] {
+    ///     let str: String = t.digits_iter().map(|(pos, digit)| {
+    ///         let point = if pos == Frac(1) { "." } else { "" };
+    ///         format!("{point}{digit}")
+    ///     }).collect();
+    ///     assert_eq!(str, t.to_string().trim_start_matches('-'));
+    /// }
+    /// ```
+    pub fn digits_iter(self) -> impl Iterator<Item = (DigitPosition, u8)> {
+        let abs = self.repr.unsigned_abs();
+        let int_part = abs / FRAC_SCALE_U128;
+        let frac_part = abs % FRAC_SCALE_U128;
+        let int_len = int_part.checked_ilog10().unwrap_or(0) + 1;
+        let mut frac_len = if frac_part == 0 { 0 } else { FRAC_PLACES };
+        let mut frac_rest = frac_part;
+        while frac_len > 0 && frac_rest.is_multiple_of(10) {
+            frac_rest /= 10;
+            frac_len -= 1;
+        }
+        let digit = |value: u128, place: u32| (value / 10_u128.pow(place) % 10) as u8;
+        let int_digits =
+            (0..int_len).rev().map(move |i| (DigitPosition::Int(i), digit(int_part, i)));
+        let frac_digits = (1..=frac_len)
+            .map(move |i| (DigitPosition::Frac(i), digit(frac_part, FRAC_PLACES - i)));
+        int_digits.chain(frac_digits)
+    }
+
+    /// Formats the integer value (not the `repr`) in the given `radix`, using lowercase letters
+    /// for digits above 9 and a leading `-` for negative values. Returns `None` if the value has
+    /// a fractional part or `radix` is not in `2..=36`.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// # use fixed_num::*;
+    /// assert_eq!(Dec19x19!(255).to_int_string_radix(16), Some("ff".to_string()));
+    /// assert_eq!(Dec19x19!(-255).to_int_string_radix(16), Some("-ff".to_string()));
+    /// assert_eq!(Dec19x19!(8).to_int_string_radix(8), Some("10".to_string()));
+    /// assert_eq!(Dec19x19!(5).to_int_string_radix(2), Some("101".to_string()));
+    /// assert_eq!(Dec19x19!(35).to_int_string_radix(36), Some("z".to_string()));
+    /// assert_eq!(Dec19x19!(1234).to_int_string_radix(10), Some("1234".to_string()));
+    /// assert_eq!(Dec19x19!(0).to_int_string_radix(16), Some("0".to_string()));
+    /// assert_eq!(Dec19x19!(-0.0).to_int_string_radix(16), Some("0".to_string()));
+    /// let max_int = Dec19x19::MAX.trunc();
+    /// assert_eq!(max_int.to_int_string_radix(16), Some("ec1e4a7db69561a5".to_string()));
+    /// assert_eq!(max_int.to_int_string_radix(10), Some(max_int.to_string()));
+    ///
+    /// assert_eq!(Dec19x19!(255.5).to_int_string_radix(16), None);
+    /// assert_eq!(Dec19x19::SMALLEST_STEP.to_int_string_radix(16), None);
+    /// assert_eq!(Dec19x19::MAX.to_int_string_radix(16), None);
+    /// assert_eq!(Dec19x19!(255).to_int_string_radix(1), None);
+    /// assert_eq!(Dec19x19!(255).to_int_string_radix(37), None);
+    /// ```
+    #[must_use]
+    pub fn to_int_string_radix(self, radix: u32) -> Option<String> {
+        if !(2..=36).contains(&radix) || self.repr % FRAC_SCALE_I128 != 0 {
+            return None;
+        }
+        let mut int_part = self.repr.unsigned_abs() / FRAC_SCALE_U128;
+        let mut digits = Vec::new();
+        loop {
+            digits.push(char::from_digit((int_part % radix as u128) as u32, radix)?);
+            int_part /= radix as u128;
+            if int_part == 0 {
+                break;
+            }
+        }
+        if self.repr < 0 {
+            digits.push('-');
+        }
+        Some(digits.iter().rev().collect())
+    }
+}
+
 impl std::fmt::Debug for Dec19x19 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self, f)
@@ -1987,11 +7091,9 @@ impl Format for Dec19x19 {
             .trim_end_matches('0')
             .to_string();
 
-        if let Some(prec) = f.precision {
-            if frac_str.len() < prec {
-                let zeros_needed = prec - frac_str.len();
-                frac_str.push_str(&"0".repeat(zeros_needed));
-            }
+        if let Some(prec) = f.precision && frac_str.len() < prec {
+            let zeros_needed = prec - frac_str.len();
+            frac_str.push_str(&"0".repeat(zeros_needed));
         }
 
         let int_str = int_part.abs().to_string();
@@ -2006,12 +7108,11 @@ impl Format for Dec19x19 {
             result.push('+');
         }
 
+        let group_int = int_str_len > f.min_group_digits;
         for (i, c) in int_str.chars().enumerate() {
             let j = int_str_len - i;
-            if i != 0 && j > 0 && j % 3 == 0 {
-                if let Some(sep) = f.separator {
-                    result.push(sep);
-                }
+            if group_int && i != 0 && j > 0 && j.is_multiple_of(3) && let Some(sep) = f.separator {
+                result.push(sep);
             }
             result.push(c);
         }
@@ -2019,10 +7120,8 @@ impl Format for Dec19x19 {
         if !frac_str.is_empty() {
             result.push('.');
             for (i, c) in frac_str.chars().enumerate() {
-                if i > 0 && i % 3 == 0 {
-                    if let Some(sep) = f.separator {
-                        result.push(sep);
-                    }
+                if i > 0 && i.is_multiple_of(3) && let Some(sep) = f.separator {
+                    result.push(sep);
                 }
                 result.push(c);
             }
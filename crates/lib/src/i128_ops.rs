@@ -55,6 +55,32 @@ pub const P36: i128 = P35 * 10;
 pub const P37: i128 = P36 * 10;
 pub const P38: i128 = P37 * 10;
 
+/// Returns `10^n` from the [`POW10`] table, or `None` if `n` is above 38, as `10^39` does not
+/// fit in `i128`.
+///
+/// # Tests
+///
+/// ```
+/// use fixed_num::i128_ops::*;
+///
+/// assert_eq!(pow10(0), Some(1));
+/// assert_eq!(pow10(1), Some(10));
+/// assert_eq!(pow10(19), Some(10_000_000_000_000_000_000));
+/// assert_eq!(pow10(38), Some(P38));
+/// assert_eq!(pow10(39), None);
+/// assert_eq!(pow10(u32::MAX), None);
+/// for n in 0..=38 {
+///     assert_eq!(pow10(n), Some(10_i128.pow(n)));
+/// }
+/// ```
+#[inline(always)]
+pub const fn pow10(n: u32) -> Option<i128> {
+    if n as usize >= POW10.len() {
+        return None;
+    }
+    Some(POW10[n as usize])
+}
+
 /// Get the scale factor for rounding to a given number of digits
 #[inline(always)]
 pub(crate) const fn scale_for(digits: i64) -> i128 {
@@ -63,6 +89,32 @@ pub(crate) const fn scale_for(digits: i64) -> i128 {
     POW10[idx]
 }
 
+/// Get the scale factor for rounding to a given number of digits, like `scale_for`, but returns
+/// `None` if `digits` is outside of `-19..=19` instead of clamping it.
+///
+/// # Tests
+///
+/// ```
+/// use fixed_num::i128_ops::*;
+///
+/// assert_eq!(checked_scale_for(19), Some(1));
+/// assert_eq!(checked_scale_for(2), Some(P17));
+/// assert_eq!(checked_scale_for(0), Some(P19));
+/// assert_eq!(checked_scale_for(-2), Some(P21));
+/// assert_eq!(checked_scale_for(-19), Some(P38));
+/// assert_eq!(checked_scale_for(20), None);
+/// assert_eq!(checked_scale_for(-20), None);
+/// assert_eq!(checked_scale_for(i64::MAX), None);
+/// assert_eq!(checked_scale_for(i64::MIN), None);
+/// ```
+#[inline(always)]
+pub const fn checked_scale_for(digits: i64) -> Option<i128> {
+    if digits < -19 || digits > 19 {
+        return None;
+    }
+    Some(POW10[(19 - digits) as usize])
+}
+
 /// Returns the number of decimal digits in an `i128`.
 ///
 /// This function calculates how many digits are needed to represent the absolute value of the input
@@ -84,7 +136,7 @@ pub(crate) const fn scale_for(digits: i64) -> i128 {
 /// assert_eq!(digit_count(i128::MIN), 39);
 /// ```
 #[expect(clippy::cognitive_complexity)]
-#[expect(clippy::collapsible_else_if)]
+#[allow(clippy::collapsible_else_if)]
 #[inline(always)]
 pub const fn digit_count(n: i128) -> i32 {
     if n == i128::MIN {
@@ -154,3 +206,57 @@ pub const fn digit_count(n: i128) -> i32 {
         }
     }
 }
+
+/// Returns the number of decimal digits in a `u128`, like [`digit_count`] for unsigned
+/// magnitudes. The result is always in the range `1..=39`, inclusive. Values that fit in `i128`
+/// use the balanced tree of comparisons of [`digit_count`], and all larger values have 39 digits,
+/// as they are above `10^38`.
+///
+/// # Tests
+///
+/// ```
+/// use fixed_num::i128_ops::*;
+///
+/// assert_eq!(digit_count_u128(0), 1);
+/// for i in 1..=38 {
+///     let j = i as i32;
+///     let pow = POW10[i] as u128;
+///     assert_eq!(digit_count_u128(pow - 1), j);
+///     assert_eq!(digit_count_u128(pow),     j + 1);
+/// }
+/// assert_eq!(digit_count_u128(i128::MAX as u128), 39);
+/// assert_eq!(digit_count_u128(i128::MAX as u128 + 1), 39);
+/// assert_eq!(digit_count_u128(i128::MIN.unsigned_abs()), 39);
+/// assert_eq!(digit_count_u128(u128::MAX), 39);
+/// ```
+#[inline(always)]
+pub const fn digit_count_u128(n: u128) -> i32 {
+    if n > i128::MAX as u128 {
+        return 39;
+    }
+    digit_count(n as i128)
+}
+
+/// Returns the sign of a raw [`Dec19x19`](crate::Dec19x19) representation: `1` if positive, `0`
+/// if zero, and `-1` if negative. Useful when working with stored representations without
+/// constructing the number, the same as [`Dec19x19::sign`](crate::Dec19x19::sign).
+///
+/// # Tests
+///
+/// ```
+/// use fixed_num::i128_ops::*;
+///
+/// assert_eq!(sign_of_repr(1), 1);
+/// assert_eq!(sign_of_repr(12_500_000_000_000_000_000), 1);
+/// assert_eq!(sign_of_repr(i128::MAX), 1);
+/// assert_eq!(sign_of_repr(0), 0);
+/// assert_eq!(sign_of_repr(-1), -1);
+/// assert_eq!(sign_of_repr(-12_500_000_000_000_000_000), -1);
+/// assert_eq!(sign_of_repr(i128::MIN), -1);
+/// const SIGN: i8 = sign_of_repr(-7);
+/// assert_eq!(SIGN, -1);
+/// ```
+#[inline(always)]
+pub const fn sign_of_repr(repr: i128) -> i8 {
+    repr.signum() as i8
+}
@@ -20,10 +20,15 @@
 #[allow(unused_extern_crates)]
 extern crate self as fixed_num;
 
+// Used in doc tests only.
+#[cfg(test)]
+use validator as _;
+
 pub mod ops;
 pub mod dec19x19;
 pub mod i128_ops;
 mod serde;
+mod rust_decimal;
 
 pub use dec19x19::Dec19x19;
 
@@ -60,3 +65,17 @@ impl UnwrapAll for Dec19x19 {
         self
     }
 }
+
+impl UnwrapAll for bool {
+    type Output = Self;
+    fn unwrap_all(self) -> Self::Output {
+        self
+    }
+}
+
+impl<A, B> UnwrapAll for (A, B) {
+    type Output = Self;
+    fn unwrap_all(self) -> Self::Output {
+        self
+    }
+}
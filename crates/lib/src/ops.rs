@@ -27,7 +27,11 @@ pub mod traits {
     pub use super::HasMax as _;
     pub use super::HasMin as _;
     pub use super::Signum as _;
+    pub use super::CheckedNeg as _;
+    pub use super::SaturatingNeg as _;
+    pub use super::WrappingNeg as _;
     pub use super::Abs as _;
+    pub use super::CheckedAbs as _;
     pub use super::UncheckedAdd as _;
     pub use super::CheckedAdd as _;
     pub use super::SaturatingAdd as _;
@@ -50,12 +54,26 @@ pub mod traits {
     pub use super::RoundTo as _;
     pub use super::UncheckedSqrt as _;
     pub use super::CheckedSqrt as _;
+    pub use super::UncheckedHypot as _;
+    pub use super::CheckedHypot as _;
     pub use super::UncheckedPow as _;
     pub use super::CheckedPow as _;
+    pub use super::SaturatingPow as _;
+    pub use super::ScaleByPow10 as _;
     pub use super::UncheckedLog10Floor as _;
     pub use super::CheckedLog10Floor as _;
     pub use super::UncheckedLn as _;
     pub use super::CheckedLn as _;
+    pub use super::UncheckedLn1p as _;
+    pub use super::CheckedLn1p as _;
+    pub use super::UncheckedExp as _;
+    pub use super::CheckedExp as _;
+    pub use super::UncheckedExp10 as _;
+    pub use super::CheckedExp10 as _;
+    pub use super::UncheckedExp2 as _;
+    pub use super::CheckedExp2 as _;
+    pub use super::UncheckedExpM1 as _;
+    pub use super::CheckedExpM1 as _;
 }
 
 // ==============
@@ -71,6 +89,7 @@ pub mod traits {
 pub trait HasMax: Sized {
     const MAX: Self;
     #[allow(clippy::wrong_self_convention)]
+    #[must_use]
     fn is_max(self) -> bool;
 }
 
@@ -87,6 +106,7 @@ pub trait HasMax: Sized {
 pub trait HasMin: Sized {
     const MIN: Self;
     #[allow(clippy::wrong_self_convention)]
+    #[must_use]
     fn is_min(self) -> bool;
 }
 
@@ -101,15 +121,64 @@ pub trait HasMin: Sized {
 /// - `0.0` if zero,
 /// - `-1.0` if negative.
 ///
+/// # Zero
+///
+/// Zero has no sign: `signum` returns `0.0` for it, and so do the other sign helpers of the
+/// implementors (e.g. `Dec19x19::sign` and `Dec19x19::cmp_zero`). The only exception is
+/// `Dec19x19::signum_nonzero`, which treats zero as positive so that its result can always be
+/// used as a divisor or multiplier without collapsing to zero.
+///
 /// # Panics
 ///
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait Signum {
+    #[must_use]
     fn signum(self) -> Self;
+    #[must_use]
     fn signum_i128(self) -> i128;
 }
 
+// ===========
+// === Neg ===
+// ===========
+
+/// ✅ Checked negation. Returns `None` if the value is the minimum representable number, whose
+/// negation is not representable. Mirrors [`i128::checked_neg`].
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedNeg: Sized {
+    #[must_use]
+    fn checked_neg(self) -> Option<Self>;
+}
+
+/// ✅ Saturating negation. If the value is the minimum representable number, returns the nearest
+/// valid value (e.g. `Self::MAX`). Mirrors [`i128::saturating_neg`].
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait SaturatingNeg {
+    #[must_use]
+    fn saturating_neg(self) -> Self;
+}
+
+/// ✅ Wrapping negation. If the value is the minimum representable number, returns it unchanged.
+/// Mirrors [`i128::wrapping_neg`].
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait WrappingNeg {
+    #[must_use]
+    fn wrapping_neg(self) -> Self;
+}
+
 // ===========
 // === Abs ===
 // ===========
@@ -122,9 +191,22 @@ pub trait Signum {
 /// nearest valid value (e.g. `Self::MAX`).
 #[cfg_attr(nightly, const_trait)]
 pub trait Abs {
+    #[must_use]
     fn abs(self) -> Self;
 }
 
+/// ✅ The absolute value of `self`, or `None` if the value is the minimum representable number,
+/// whose absolute value is not representable. Mirrors [`i128::checked_abs`].
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedAbs: Sized {
+    #[must_use]
+    fn checked_abs(self) -> Option<Self>;
+}
+
 // ===========
 // === Add ===
 // ===========
@@ -137,6 +219,7 @@ pub trait Abs {
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedAdd<Rhs = Self> {
     type Output;
+    #[must_use]
     fn unchecked_add(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -145,9 +228,26 @@ pub trait UncheckedAdd<Rhs = Self> {
 /// # Panics
 ///
 /// This function never panics.
+///
+/// # Tests
+///
+/// Discarding the result is reported by the `unused_must_use` lint.
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use fixed_num::*;
+/// Dec19x19!(1).checked_add(Dec19x19!(2));
+/// ```
+///
+/// ```
+/// #![deny(unused_must_use)]
+/// # use fixed_num::*;
+/// let _ = Dec19x19!(1).checked_add(Dec19x19!(2));
+/// ```
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedAdd<Rhs = Self> {
     type Output;
+    #[must_use]
     fn checked_add(self, rhs: Rhs) -> Option<Self::Output>;
 }
 
@@ -159,6 +259,7 @@ pub trait CheckedAdd<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait SaturatingAdd<Rhs = Self> {
     type Output;
+    #[must_use]
     fn saturating_add(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -174,6 +275,7 @@ pub trait SaturatingAdd<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedSub<Rhs = Self> {
     type Output;
+    #[must_use]
     fn unchecked_sub(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -185,6 +287,7 @@ pub trait UncheckedSub<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedSub<Rhs = Self> {
     type Output;
+    #[must_use]
     fn checked_sub(self, rhs: Rhs) -> Option<Self::Output>;
 }
 
@@ -196,6 +299,7 @@ pub trait CheckedSub<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait SaturatingSub<Rhs = Self> {
     type Output;
+    #[must_use]
     fn saturating_sub(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -211,6 +315,7 @@ pub trait SaturatingSub<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedMul<Rhs = Self> {
     type Output;
+    #[must_use]
     fn unchecked_mul(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -222,6 +327,7 @@ pub trait UncheckedMul<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedMul<Rhs = Self> {
     type Output;
+    #[must_use]
     fn checked_mul(self, rhs: Rhs) -> Option<Self::Output>;
 }
 
@@ -233,6 +339,7 @@ pub trait CheckedMul<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait SaturatingMul<Rhs = Self> {
     type Output;
+    #[must_use]
     fn saturating_mul(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -248,6 +355,7 @@ pub trait SaturatingMul<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedDiv<Rhs = Self> {
     type Output;
+    #[must_use]
     fn unchecked_div(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -259,6 +367,7 @@ pub trait UncheckedDiv<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedDiv<Rhs = Self> {
     type Output;
+    #[must_use]
     fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
 }
 
@@ -271,6 +380,7 @@ pub trait CheckedDiv<Rhs = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait SaturatingDiv<Rhs = Self> {
     type Output;
+    #[must_use]
     fn saturating_div(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -285,6 +395,7 @@ pub trait SaturatingDiv<Rhs = Self> {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait Trunc {
+    #[must_use]
     fn trunc(self) -> Self;
 }
 
@@ -295,6 +406,7 @@ pub trait Trunc {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait TruncTo {
+    #[must_use]
     fn trunc_to(self, digits: i64) -> Self;
 }
 
@@ -310,6 +422,7 @@ pub trait TruncTo {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait Floor {
+    #[must_use]
     fn floor(self) -> Self;
 }
 
@@ -321,6 +434,7 @@ pub trait Floor {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait FloorTo {
+    #[must_use]
     fn floor_to(self, digits: i64) -> Self;
 }
 
@@ -336,6 +450,7 @@ pub trait FloorTo {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait Ceil {
+    #[must_use]
     fn ceil(self) -> Self;
 }
 
@@ -347,6 +462,7 @@ pub trait Ceil {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait CeilTo {
+    #[must_use]
     fn ceil_to(self, digits: i64) -> Self;
 }
 
@@ -369,6 +485,7 @@ pub trait CeilTo {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait Round {
+    #[must_use]
     fn round(self) -> Self;
 }
 
@@ -381,6 +498,7 @@ pub trait Round {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait RoundTo {
+    #[must_use]
     fn round_to(self, digits: i64) -> Self;
 }
 
@@ -395,6 +513,7 @@ pub trait RoundTo {
 /// Panics if `self` is negative.
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedSqrt {
+    #[must_use]
     fn unchecked_sqrt(self) -> Self;
 }
 
@@ -405,9 +524,39 @@ pub trait UncheckedSqrt {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedSqrt: Sized {
+    #[must_use]
     fn checked_sqrt(self) -> Option<Self>;
 }
 
+// =============
+// === Hypot ===
+// =============
+
+/// Returns `sqrt(self² + other²)` without intermediate overflow.
+///
+/// # Panics
+///
+/// Panics if the result overflows.
+#[cfg_attr(nightly, const_trait)]
+pub trait UncheckedHypot<Rhs = Self> {
+    type Output;
+    #[must_use]
+    fn unchecked_hypot(self, other: Rhs) -> Self::Output;
+}
+
+/// ✅ Returns `sqrt(self² + other²)` without intermediate overflow, or `None` if the result
+/// overflows.
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedHypot<Rhs = Self> {
+    type Output;
+    #[must_use]
+    fn checked_hypot(self, other: Rhs) -> Option<Self::Output>;
+}
+
 // ===========
 // === Pow ===
 // ===========
@@ -420,10 +569,11 @@ pub trait CheckedSqrt: Sized {
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedPow<Exp = Self> {
     type Output;
+    #[must_use]
     fn unchecked_pow(self, exp: Exp) -> Self::Output;
 }
 
-/// ✅ aises `self` to the power of `exp`, returning `None` on overflow or invalid input.
+/// ✅ Raises `self` to the power of `exp`, returning `None` on overflow or invalid input.
 ///
 /// # Panics
 ///
@@ -431,9 +581,60 @@ pub trait UncheckedPow<Exp = Self> {
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedPow<Rhs = Self> {
     type Output;
+    #[must_use]
     fn checked_pow(self, exp: Rhs) -> Option<Self::Output>;
 }
 
+/// ✅ Raises `self` to the power of `exp`, saturating on overflow. For negative bases, the sign of
+/// the result alternates with the parity of `exp`, so the result saturates at `Self::MIN` for odd
+/// `exp` and at `Self::MAX` otherwise. If `exp` is negative and `self` is zero, returns
+/// `Self::MAX`.
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait SaturatingPow<Rhs = Self> {
+    type Output;
+    #[must_use]
+    fn saturating_pow(self, exp: Rhs) -> Self::Output;
+}
+
+// ====================
+// === ScaleByPow10 ===
+// ====================
+
+/// Multiplication of `self` by `10^n`, a cheap decimal shift for fixed-point numbers.
+#[cfg_attr(nightly, const_trait)]
+pub trait ScaleByPow10: Sized {
+    /// Multiplies `self` by `10^n`. For negative `n`, the digits shifted below the precision of
+    /// the type are truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow.
+    #[must_use]
+    fn scale_by_pow10(self, n: i32) -> Self;
+
+    /// ✅ Multiplies `self` by `10^n`. Returns `None` if the result overflows for positive `n`,
+    /// or if nonzero digits would be discarded for negative `n`, so the result is always exact.
+    ///
+    /// # Panics
+    ///
+    /// This function never panics.
+    #[must_use]
+    fn checked_scale_by_pow10(self, n: i32) -> Option<Self>;
+
+    /// ✅ Multiplies `self` by `10^n`, saturating at the numeric bounds on overflow. For negative
+    /// `n`, the digits shifted below the precision of the type are truncated.
+    ///
+    /// # Panics
+    ///
+    /// This function never panics.
+    #[must_use]
+    fn saturating_scale_by_pow10(self, n: i32) -> Self;
+}
+
 // ==================
 // === Log10Floor ===
 // ==================
@@ -445,6 +646,7 @@ pub trait CheckedPow<Rhs = Self> {
 /// Panics if `self` is zero or negative.
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedLog10Floor {
+    #[must_use]
     fn unchecked_log10_floor(self) -> Self;
 }
 
@@ -456,6 +658,7 @@ pub trait UncheckedLog10Floor {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedLog10Floor: Sized {
+    #[must_use]
     fn checked_log10_floor(self) -> Option<Self>;
 }
 
@@ -470,6 +673,7 @@ pub trait CheckedLog10Floor: Sized {
 /// Panics if `self` is zero or negative.
 #[cfg_attr(nightly, const_trait)]
 pub trait UncheckedLn {
+    #[must_use]
     fn unchecked_ln(self) -> Self;
 }
 
@@ -480,5 +684,141 @@ pub trait UncheckedLn {
 /// This function never panics.
 #[cfg_attr(nightly, const_trait)]
 pub trait CheckedLn: Sized {
+    #[must_use]
     fn checked_ln(self) -> Option<Self>;
 }
+
+// ============
+// === Ln1p ===
+// ============
+
+/// Returns `ln(1 + self)`, accurate even if `self` is close to zero.
+///
+/// # Panics
+///
+/// Panics if `self` is less than or equal to `-1`.
+#[cfg_attr(nightly, const_trait)]
+pub trait UncheckedLn1p {
+    #[must_use]
+    fn unchecked_ln_1p(self) -> Self;
+}
+
+/// ✅ Returns `ln(1 + self)`, accurate even if `self` is close to zero, or `None` if `self` is less
+/// than or equal to `-1`.
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedLn1p: Sized {
+    #[must_use]
+    fn checked_ln_1p(self) -> Option<Self>;
+}
+
+// ===========
+// === Exp ===
+// ===========
+
+/// Returns `e^self`. Returns zero if the result is smaller than the smallest representable step.
+///
+/// # Panics
+///
+/// Panics if the result overflows.
+#[cfg_attr(nightly, const_trait)]
+pub trait UncheckedExp {
+    #[must_use]
+    fn unchecked_exp(self) -> Self;
+}
+
+/// ✅ Returns `e^self`, or `None` if the result overflows. Returns zero if the result is smaller
+/// than the smallest representable step.
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedExp: Sized {
+    #[must_use]
+    fn checked_exp(self) -> Option<Self>;
+}
+
+// =============
+// === Exp10 ===
+// =============
+
+/// Returns `10^self`. Returns zero if the result is smaller than the smallest representable step.
+///
+/// # Panics
+///
+/// Panics if the result overflows.
+#[cfg_attr(nightly, const_trait)]
+pub trait UncheckedExp10 {
+    #[must_use]
+    fn unchecked_exp10(self) -> Self;
+}
+
+/// ✅ Returns `10^self`, or `None` if the result overflows. Returns zero if the result is smaller
+/// than the smallest representable step.
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedExp10: Sized {
+    #[must_use]
+    fn checked_exp10(self) -> Option<Self>;
+}
+
+// ============
+// === Exp2 ===
+// ============
+
+/// Returns `2^self`. Returns zero if the result is smaller than the smallest representable step.
+///
+/// # Panics
+///
+/// Panics if the result overflows.
+#[cfg_attr(nightly, const_trait)]
+pub trait UncheckedExp2 {
+    #[must_use]
+    fn unchecked_exp2(self) -> Self;
+}
+
+/// ✅ Returns `2^self`, or `None` if the result overflows. Returns zero if the result is smaller
+/// than the smallest representable step.
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedExp2: Sized {
+    #[must_use]
+    fn checked_exp2(self) -> Option<Self>;
+}
+
+// =============
+// === ExpM1 ===
+// =============
+
+/// Returns `e^self - 1`, accurate even if `self` is close to zero.
+///
+/// # Panics
+///
+/// Panics if the result overflows.
+#[cfg_attr(nightly, const_trait)]
+pub trait UncheckedExpM1 {
+    #[must_use]
+    fn unchecked_exp_m1(self) -> Self;
+}
+
+/// ✅ Returns `e^self - 1`, accurate even if `self` is close to zero, or `None` if the result
+/// overflows.
+///
+/// # Panics
+///
+/// This function never panics.
+#[cfg_attr(nightly, const_trait)]
+pub trait CheckedExpM1: Sized {
+    #[must_use]
+    fn checked_exp_m1(self) -> Option<Self>;
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "rust_decimal")]
+use crate::*;
+use crate::dec19x19::ConversionError;
+use fixed_num_helper::FRAC_PLACES;
+use ::rust_decimal::Decimal;
+
+// ===========================
+// === Dec19x19 -> Decimal ===
+// ===========================
+
+/// Converts to [`rust_decimal::Decimal`]. Trailing fractional zeros are dropped to reduce the
+/// scale, so the conversion fails only if the remaining significand does not fit in the 96 bits
+/// supported by [`rust_decimal::Decimal`].
+///
+/// # Tests
+///
+/// ```
+/// # use fixed_num::*;
+/// # use fixed_num::dec19x19::ConversionError;
+/// use rust_decimal::Decimal;
+/// assert_eq!(Decimal::try_from(Dec19x19!(1.5)), Ok(Decimal::new(15, 1)));
+/// assert_eq!(Decimal::try_from(Dec19x19!(-1.5)), Ok(Decimal::new(-15, 1)));
+/// assert_eq!(Decimal::try_from(Dec19x19!(0)), Ok(Decimal::ZERO));
+/// assert_eq!(
+///     Decimal::try_from(Dec19x19::SMALLEST_STEP),
+///     Ok(Decimal::new(1, 19))
+/// );
+/// assert_eq!(
+///     Decimal::try_from(Dec19x19::MAX_INT),
+///     Ok(Decimal::from(17_014_118_346_046_923_173_u64))
+/// );
+/// assert_eq!(
+///     Decimal::try_from(Dec19x19!(1_000_000_000.000_000_000_1)),
+///     Ok(Decimal::from_i128_with_scale(10_000_000_000_000_000_001, 10))
+/// );
+/// // 30 significant digits exceed the 96-bit significand.
+/// assert_eq!(
+///     Decimal::try_from(Dec19x19!(10_000_000_000.000_000_000_000_000_000_1)),
+///     Err(ConversionError::OutOfBounds)
+/// );
+/// assert_eq!(Decimal::try_from(Dec19x19::MAX), Err(ConversionError::OutOfBounds));
+/// assert_eq!(Decimal::try_from(Dec19x19::MIN), Err(ConversionError::OutOfBounds));
+/// ```
+impl TryFrom<Dec19x19> for Decimal {
+    type Error = ConversionError;
+    fn try_from(value: Dec19x19) -> Result<Self, Self::Error> {
+        let mut num = value.repr;
+        let mut scale = FRAC_PLACES;
+        while scale > 0 && num % 10 == 0 {
+            num /= 10;
+            scale -= 1;
+        }
+        Self::try_from_i128_with_scale(num, scale).map_err(|_| ConversionError::OutOfBounds)
+    }
+}
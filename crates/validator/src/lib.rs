@@ -37,9 +37,11 @@ A: Rand + Display + FromStr<Err:Debug>,
 B: FromStr<Err:Debug> {
     if cfg.seed == 0 { cfg.seed = 7; }
     let vec_str = series_str::<A>(cfg);
-    let a_vec = vec_str.iter().map(|a| A::from_str(a).unwrap()).collect::<Vec<_>>();
-    let b_vec = vec_str.iter().map(|a| B::from_str(a).unwrap()).collect::<Vec<_>>();
-    a_vec.into_iter().zip(b_vec.into_iter()).collect::<Vec<_>>()
+    let parse_a = |a: &String| A::from_str(a).expect("Invalid series value");
+    let parse_b = |a: &String| B::from_str(a).expect("Invalid series value");
+    let a_vec = vec_str.iter().map(parse_a).collect::<Vec<_>>();
+    let b_vec = vec_str.iter().map(parse_b).collect::<Vec<_>>();
+    a_vec.into_iter().zip(b_vec).collect::<Vec<_>>()
 }
 
 pub fn series_pair2<A, B>(mut cfg1: Series, mut cfg2: Series) -> Vec<((A, B), (A, B))> where
@@ -47,7 +49,7 @@ A: Rand + Display + FromStr<Err:Debug>,
 B: FromStr<Err:Debug> {
     if cfg1.seed == 0 { cfg1.seed = 7; }
     if cfg2.seed == 0 { cfg2.seed = 17; }
-    series_pair1(cfg1).into_iter().zip(series_pair1(cfg2).into_iter()).collect()
+    series_pair1(cfg1).into_iter().zip(series_pair1(cfg2)).collect()
 }
 
 pub fn fuzzy1<A, B>(cfg1: Series, f: impl Fn(A, B)) where
@@ -67,7 +69,7 @@ B: FromStr<Err:Debug> {
 }
 
 pub fn should_panic<T: Debug>(f: impl FnOnce() -> T + std::panic::UnwindSafe, desc: &str) {
-    let result = std::panic::catch_unwind(|| f());
+    let result = std::panic::catch_unwind(f);
     assert!(result.is_err(), "Expected panic, but got: {result:?} in {desc}");
 }
 
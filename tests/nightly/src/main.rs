@@ -1,4 +1,4 @@
-/// This tests compilation on the nightly toolchain.
+//! This tests compilation on the nightly toolchain.
 
 use fixed_num::*;
 
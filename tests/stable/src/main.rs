@@ -1,4 +1,4 @@
-/// This tests compilation on the stable toolchain.
+//! This tests compilation on the stable toolchain.
 
 use fixed_num::*;
 